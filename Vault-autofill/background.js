@@ -263,7 +263,8 @@ chrome.runtime.onMessage.addListener((msg, sender, sendResp) => {
                     name: display_name || username || (typeof aliasResp === "string" ? aliasResp : null),
                     pii: { email: (aliasResp && (aliasResp.alias || aliasResp.email)) || null, phone: null },
                     site_type: "generic",
-                    trust_level: 0
+                    trust_level: 0,
+                    alias_id: (aliasResp && aliasResp.alias && aliasResp.alias.id) || null
                 });
 
                 let item_id = pickId(identityResp);
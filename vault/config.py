@@ -0,0 +1,132 @@
+"""
+config.py
+
+Settings loaded from the process environment (and `.env` via python-dotenv).
+
+SimpleLogin accounts:
+- Single account: SL_API_KEY (and optionally SL_BASE_URL) -> account named "default".
+- Several accounts: SL_ACCOUNTS=personal,work plus SL_<NAME>_API_KEY / SL_<NAME>_BASE_URL
+  for each name, and SL_DEFAULT_ACCOUNT=<name> to pick the default.
+"""
+
+import os
+from dataclasses import dataclass, field, fields
+from typing import Dict, List, Optional, Tuple
+
+from dotenv import load_dotenv
+
+from vault.errors import NotFound
+
+DEFAULT_SL_BASE_URL = "https://api.simplelogin.io"
+DEFAULT_ACCOUNT_NAME = "default"
+
+
+@dataclass
+class AccountConfig:
+    api_key: Optional[str] = None
+    base_url: str = DEFAULT_SL_BASE_URL
+    default: bool = False
+
+
+@dataclass
+class Config:
+    forward_email_api_key: Optional[str] = None
+    sl_email: Optional[str] = None
+    sl_password: Optional[str] = None
+    sl_device: Optional[str] = None
+    accounts: Dict[str, AccountConfig] = field(default_factory=dict)
+
+    @classmethod
+    def from_env(cls) -> "Config":
+        load_dotenv()
+
+        accounts: Dict[str, AccountConfig] = {}
+        names = [n.strip() for n in os.getenv("SL_ACCOUNTS", "").split(",") if n.strip()]
+        if names:
+            default_name = os.getenv("SL_DEFAULT_ACCOUNT", names[0])
+            for name in names:
+                prefix = f"SL_{name.upper()}_"
+                accounts[name] = AccountConfig(
+                    api_key=os.getenv(prefix + "API_KEY"),
+                    base_url=os.getenv(prefix + "BASE_URL", DEFAULT_SL_BASE_URL),
+                    default=(name == default_name),
+                )
+        elif os.getenv("SL_API_KEY"):
+            accounts[DEFAULT_ACCOUNT_NAME] = AccountConfig(
+                api_key=os.getenv("SL_API_KEY"),
+                base_url=os.getenv("SL_BASE_URL", DEFAULT_SL_BASE_URL),
+                default=True,
+            )
+
+        return cls(
+            forward_email_api_key=os.getenv("FORWARD_EMAIL_API_KEY"),
+            sl_email=os.getenv("SL_EMAIL"),
+            sl_password=os.getenv("SL_PASSWORD"),
+            sl_device=os.getenv("SL_DEVICE"),
+            accounts=accounts,
+        )
+
+    @staticmethod
+    def merge(base: "Config", overlay: "Config") -> "Config":
+        """
+        Layers `overlay` on top of `base` (e.g. environment over a config file): each field
+        comes from overlay when it is set and non-empty, otherwise from base.
+        `accounts` is taken as a whole, not merged per account.
+        """
+        merged = {}
+        for f in fields(Config):
+            value = getattr(overlay, f.name)
+            merged[f.name] = value if value else getattr(base, f.name)
+        return Config(**merged)
+
+    def account(self, name: str) -> Optional[AccountConfig]:
+        return self.accounts.get(name)
+
+    def default_account(self) -> AccountConfig:
+        """
+        The account flagged `default`; a lone account counts as the default.
+        """
+        for acc in self.accounts.values():
+            if acc.default:
+                return acc
+        if len(self.accounts) == 1:
+            return next(iter(self.accounts.values()))
+        raise NotFound("No default SimpleLogin account configured")
+
+    def to_env_string(self, include_secrets: bool = False) -> str:
+        """
+        Renders the config as `export KEY="value"` lines that from_env() reads back.
+        API keys and passwords are left out unless include_secrets is True.
+        """
+        # (name, value, is_secret)
+        entries: List[Tuple[str, Optional[str], bool]] = [
+            ("FORWARD_EMAIL_API_KEY", self.forward_email_api_key, True),
+            ("SL_EMAIL", self.sl_email, False),
+            ("SL_PASSWORD", self.sl_password, True),
+            ("SL_DEVICE", self.sl_device, False),
+        ]
+
+        if list(self.accounts) == [DEFAULT_ACCOUNT_NAME]:
+            acc = self.accounts[DEFAULT_ACCOUNT_NAME]
+            entries += [("SL_API_KEY", acc.api_key, True), ("SL_BASE_URL", acc.base_url, False)]
+        elif self.accounts:
+            entries.append(("SL_ACCOUNTS", ",".join(self.accounts), False))
+            default_name = next((n for n, a in self.accounts.items() if a.default), None)
+            entries.append(("SL_DEFAULT_ACCOUNT", default_name, False))
+            for name, acc in self.accounts.items():
+                prefix = f"SL_{name.upper()}_"
+                entries += [(prefix + "API_KEY", acc.api_key, True), (prefix + "BASE_URL", acc.base_url, False)]
+
+        lines = [
+            f'export {key}="{_shell_escape(value)}"'
+            for key, value, is_secret in entries
+            if value is not None and (include_secrets or not is_secret)
+        ]
+        return "\n".join(lines) + ("\n" if lines else "")
+
+
+def _shell_escape(value: str) -> str:
+    # characters that stay special inside double quotes
+    for ch in ("\\", '"', "$", "`"):
+        value = value.replace(ch, "\\" + ch)
+    return value
@@ -1,6 +1,25 @@
+import asyncio
+import csv
+import re
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from enum import Enum
+
 import httpx
 from pydantic import BaseModel
-from typing import Optional, List
+from typing import AsyncIterator, Iterator, Optional, List, TextIO, Tuple
+
+from email_aliases.http import ConditionalResponse, HttpClient, Modified, NotModified, parse_response
+from profile.models import Profile
+from utils.random import random_delay
+from vault.errors import ApiError, InvalidArgument, NetworkError, NotSupported, VaultError
+
+RANDOM_ALIAS_MODES = ("uuid", "word")
+_ALIAS_PREFIX_INVALID = re.compile(r"[^a-z0-9_-]+")
+ALIAS_PAGE_SIZE = 20  # aliases per page returned by SimpleLogin
+# random pause before each request of a batch operation, so bulk runs don't form a fixed pattern
+BATCH_DELAY_MIN_MS = 50
+BATCH_DELAY_MAX_MS = 300
 
 
 # ---------------------------------------------------------------------------
@@ -12,16 +31,164 @@ class Mailbox(BaseModel):
     email: str
 
 
+class AliasActivity(BaseModel):
+    timestamp: int
+    action: str  # "forward", "block", "reply", "bounced"
+
+
 class Alias(BaseModel):
     id: int
     email: str
     enabled: bool
     mailboxes: List[Mailbox]
     name: Optional[str] = None
+    note: Optional[str] = None
+    creation_timestamp: Optional[int] = None
+    latest_activity: Optional[AliasActivity] = None
+    # activity counters returned by the list endpoint
+    nb_forward: int = 0
+    nb_block: int = 0
+    nb_reply: int = 0
+
+    @property
+    def domain(self) -> str:
+        """Everything after the last '@' of the alias email."""
+        return self.email.rpartition("@")[2]
+
+    @property
+    def local_part(self) -> str:
+        """Everything before the last '@' of the alias email."""
+        return self.email.rpartition("@")[0]
+
+    def matches_profile(self, profile: Profile) -> bool:
+        """
+        True if the alias is linked to the profile, its note mentions the profile ID,
+        or its local part starts with the profile's domain stem (see alias_prefix_from_domain).
+        Used to re-link profiles to aliases, e.g. after an import.
+        """
+        if profile.linked_alias_id == self.id:
+            return True
+        if self.note and str(profile.id) in self.note:
+            return True
+        stem = alias_prefix_from_domain(profile.domain)
+        return bool(stem) and self.local_part.lower().startswith(stem)
+
+    def is_spam_trap(self) -> bool:
+        """
+        Rough signal, not a classification: blocks outnumber forwards more than 3 to 1.
+        """
+        return self.nb_forward > 0 and self.nb_block > self.nb_forward * 3
 
 
 class AliasesResponse(BaseModel):
     aliases: List[Alias]
+    total: Optional[int] = None  # only sent by some server versions
+
+    # Iterate over the aliases directly: `for alias in response: ...`
+    # (replaces pydantic's default field iteration).
+    def __iter__(self) -> Iterator[Alias]:
+        return iter(self.aliases)
+
+    def __len__(self) -> int:
+        return len(self.aliases)
+
+    def find_by_email(self, email: str) -> Optional[Alias]:
+        """Case-insensitive lookup by alias email."""
+        target = email.casefold()
+        return next((a for a in self.aliases if a.email.casefold() == target), None)
+
+    def find_by_id(self, alias_id: int) -> Optional[Alias]:
+        return next((a for a in self.aliases if a.id == alias_id), None)
+
+    def enabled_aliases(self) -> List[Alias]:
+        return [a for a in self.aliases if a.enabled]
+
+    def disabled_aliases(self) -> List[Alias]:
+        return [a for a in self.aliases if not a.enabled]
+
+    def aliases_for_mailbox(self, mailbox_id: int) -> List[Alias]:
+        """Aliases delivering to `mailbox_id` (among possibly other mailboxes)."""
+        return [a for a in self.aliases if any(m.id == mailbox_id for m in a.mailboxes)]
+
+    def spam_trap_aliases(self) -> List[Alias]:
+        return [a for a in self.aliases if a.is_spam_trap()]
+
+
+class AliasPage(AliasesResponse):
+    """
+    One page of list_aliases. `has_more` is False once the API returns an empty page,
+    which is how it signals the end since it sends no page count.
+    """
+    page_id: int
+    has_more: bool
+
+    def next_page_id(self) -> Optional[int]:
+        """The page to request next, or None when there are no more."""
+        return self.page_id + 1 if self.has_more else None
+
+
+class AliasSortKey(str, Enum):
+    CREATED_AT = "created_at"
+    EMAIL = "email"
+    LAST_ACTIVITY = "last_activity"
+    FORWARD_COUNT = "forward_count"
+
+
+class SortedAliasesResponse(AliasesResponse):
+    sort_by: AliasSortKey
+    descending: bool = False
+
+
+class Contact(BaseModel):
+    id: int
+    contact: str
+    reverse_alias: str
+    reverse_alias_address: Optional[str] = None
+
+
+class ContactsResponse(BaseModel):
+    contacts: List[Contact]
+
+
+class AliasSuffix(BaseModel):
+    suffix: str
+    signed_suffix: str
+    is_custom: bool = False
+    is_premium: bool = False
+
+
+class AliasOptions(BaseModel):
+    can_create: bool
+    prefix_suggestion: str = ""
+    suffixes: List[AliasSuffix]
+
+
+class AliasStats(BaseModel):
+    nb_forward: int = 0
+    nb_reply: int = 0
+    nb_block: int = 0
+    nb_spam: int = 0
+
+
+class AliasDomain(BaseModel):
+    domain: str
+    is_premium: bool = False
+    is_custom: bool = False
+
+
+class ToggleResponse(BaseModel):
+    enabled: bool
+
+
+class VerifyResult(BaseModel):
+    test_email_sent: bool
+    alias_active: bool
+
+
+@dataclass
+class BatchToggleResult:
+    succeeded: List[int] = field(default_factory=list)
+    failed: List[Tuple[int, Exception]] = field(default_factory=list)
 
 
 class CreateAliasRequest(BaseModel):
@@ -36,25 +203,51 @@ class CreateRandomAliasRequest(BaseModel):
     note: Optional[str] = None
 
 
+class UpdateAliasRequest(BaseModel):
+    """Fields left as None are not sent, so the server keeps their current value."""
+    note: Optional[str] = None
+    name: Optional[str] = None
+    mailbox_ids: Optional[List[int]] = None
+    disable_pgp: Optional[bool] = None
+    pinned: Optional[bool] = None
+
+
+def alias_prefix_from_domain(domain: str) -> str:
+    """
+    Alias prefix for a site domain: scheme, path, "www." and TLD dropped, dots turned into hyphens,
+    anything else outside [a-z0-9_-] removed. Empty if nothing usable is left.
+    """
+    host = domain.strip().lower().split("://")[-1].split("/")[0].split(":")[0]
+    labels = [label for label in host.split(".") if label]
+    if len(labels) > 2 and labels[0] == "www":
+        labels = labels[1:]
+    if len(labels) > 1:
+        labels = labels[:-1]
+    return _ALIAS_PREFIX_INVALID.sub("", "-".join(labels)).strip("-_")
+
+
 # ---------------------------------------------------------------------------
 # Client Implementation
 # ---------------------------------------------------------------------------
 
 class AliasClient:
-    def __init__(self, base_url: str, api_key: Optional[str] = None, client: Optional[httpx.AsyncClient] = None):
+    def __init__(self, base_url: str, api_key: Optional[str] = None, client: Optional[HttpClient] = None):
         """
         :param base_url: The API base URL.
         :param api_key: Optional API key for authentication.
-        :param client: Optional existing httpx.AsyncClient.
+        :param client: Optional existing HTTP client (httpx.AsyncClient or compatible).
         """
         self.base_url = base_url.rstrip("/")
         self.api_key = api_key
         # Use provided client or create a new one
         self.client = client if client else httpx.AsyncClient()
 
-    async def list_aliases(self, page_id: int) -> AliasesResponse:
+    def set_api_key(self, api_key: str):
+        self.api_key = api_key
+
+    async def list_aliases(self, page_id: int) -> AliasPage:
         """
-        Fetches a list of aliases (paginated).
+        Fetches a list of aliases (paginated). Follow page.next_page_id() to walk all pages.
         """
         if not self.api_key:
             raise ValueError("API Key not set")
@@ -67,12 +260,187 @@ class AliasClient:
             response = await self.client.get(url, params=params, headers=headers)
 
             if response.status_code == 200:
-                return AliasesResponse(**response.json())
+                page = parse_response(AliasesResponse, response)
+                return AliasPage(aliases=page.aliases, total=page.total, page_id=page_id, has_more=bool(page.aliases))
             else:
-                raise Exception(f"Failed to list aliases: {response.text}")
+                raise ApiError(response.status_code, response.text)
 
         except httpx.RequestError as e:
-            raise Exception(f"Network error occurred: {e}")
+            raise NetworkError(e) from e
+
+    async def list_aliases_sorted(self, page_id: int, sort_by: AliasSortKey, descending: bool = False) -> SortedAliasesResponse:
+        """
+        Fetches a page of aliases and sorts it client-side (the API has no sort parameter).
+        Aliases missing the sort field (e.g. no activity yet) always go last.
+        """
+        page = await self.list_aliases(page_id=page_id)
+        keys = {
+            AliasSortKey.CREATED_AT: lambda a: a.creation_timestamp,
+            AliasSortKey.EMAIL: lambda a: a.email.casefold(),
+            AliasSortKey.LAST_ACTIVITY: lambda a: a.latest_activity.timestamp if a.latest_activity else None,
+            AliasSortKey.FORWARD_COUNT: lambda a: a.nb_forward,
+        }
+        key = keys[sort_by]
+        present = sorted((a for a in page.aliases if key(a) is not None), key=key, reverse=descending)
+        missing = [a for a in page.aliases if key(a) is None]
+        return SortedAliasesResponse(aliases=present + missing, total=page.total, sort_by=sort_by, descending=descending)
+
+    async def list_aliases_conditional(self, page_id: int, etag: Optional[str] = None) -> ConditionalResponse[AliasesResponse]:
+        """
+        Like list_aliases, but sends If-None-Match when `etag` is given and returns NotModified
+        on 304, so an unchanged page isn't downloaded and parsed again. Servers that don't
+        send ETags always yield Modified with etag=None.
+        """
+        if not self.api_key:
+            raise ValueError("API Key not set")
+
+        url = f"{self.base_url}/api/v2/aliases"
+        params = {"page_id": page_id}
+        headers = {"Authentication": self.api_key}
+        if etag:
+            headers["If-None-Match"] = etag
+
+        try:
+            response = await self.client.get(url, params=params, headers=headers)
+
+            if response.status_code == 304:
+                return NotModified()
+            if response.status_code == 200:
+                return Modified(parse_response(AliasesResponse, response), response.headers.get("etag"))
+            raise ApiError(response.status_code, response.text)
+
+        except httpx.RequestError as e:
+            raise NetworkError(e) from e
+
+    async def get_total_alias_count(self) -> int:
+        """
+        Quick alias count from a single page-0 request, e.g. to check a free plan's limit.
+        Exact when the server sends `total`; otherwise it is a lower bound: the size of the
+        first page, exact only below ALIAS_PAGE_SIZE. Use count_all_aliases for an exact
+        count on servers without `total`.
+        """
+        page = await self.list_aliases(page_id=0)
+        if page.total is not None:
+            return page.total
+        return len(page.aliases)
+
+    async def count_all_aliases(self) -> int:
+        """
+        Exact number of aliases by walking every page: one request per ALIAS_PAGE_SIZE aliases.
+        """
+        count = 0
+        page_id = 0
+        while page_id is not None:
+            page = await self.list_aliases(page_id=page_id)
+            count += len(page.aliases)
+            page_id = page.next_page_id()
+        return count
+
+    async def list_aliases_for_mailbox(self, mailbox_id: int, page_id: int) -> AliasesResponse:
+        """
+        Fetches a page of aliases and keeps only those delivering to `mailbox_id`.
+        The API has no mailbox filter, so filtering happens client-side: a page may
+        come back empty even though later pages still contain matches.
+        """
+        page = await self.list_aliases(page_id=page_id)
+        return AliasesResponse(aliases=page.aliases_for_mailbox(mailbox_id))
+
+    async def search_aliases(self, query: str, page_id: int = 0) -> AliasesResponse:
+        """
+        Fetches a page of aliases matching the query (server-side search).
+        """
+        if not self.api_key:
+            raise ValueError("API Key not set")
+
+        url = f"{self.base_url}/api/v2/aliases"
+        params = {"page_id": page_id}
+        headers = {"Authentication": self.api_key}
+
+        try:
+            response = await self.client.post(url, params=params, headers=headers, json={"query": query})
+
+            if response.status_code == 200:
+                return parse_response(AliasesResponse, response)
+            else:
+                raise ApiError(response.status_code, response.text)
+
+        except httpx.RequestError as e:
+            raise NetworkError(e) from e
+
+    async def iter_aliases(self, start_page: int = 0) -> AsyncIterator[Alias]:
+        """
+        Streams aliases page by page until the API returns an empty page.
+        Pages are only fetched as the caller consumes them.
+        """
+        page_id = start_page
+        while page_id is not None:
+            page = await self.list_aliases(page_id=page_id)
+            for alias in page.aliases:
+                yield alias
+            page_id = page.next_page_id()
+
+    async def export_all_aliases_csv(self, writer: TextIO) -> int:
+        """
+        Writes every alias as CSV (header row first) to `writer`, an open text file or
+        io.StringIO; open files with newline="". Pages are streamed, not collected in memory.
+        Mailboxes are joined with ";", created_at is ISO 8601 UTC. Returns the number of aliases written.
+        """
+        out = csv.writer(writer)
+        out.writerow(["id", "email", "enabled", "name", "note", "nb_forward", "nb_block", "mailboxes", "created_at"])
+        count = 0
+        async for alias in self.iter_aliases():
+            created_at = ""
+            if alias.creation_timestamp is not None:
+                created_at = datetime.fromtimestamp(alias.creation_timestamp, tz=timezone.utc).isoformat()
+            out.writerow([
+                alias.id,
+                alias.email,
+                "true" if alias.enabled else "false",
+                alias.name or "",
+                alias.note or "",
+                alias.nb_forward,
+                alias.nb_block,
+                ";".join(m.email for m in alias.mailboxes),
+                created_at,
+            ])
+            count += 1
+        return count
+
+    async def get_alias_by_email(self, email: str) -> Optional[Alias]:
+        """
+        Looks up an alias by its email address. Returns None if no alias matches.
+
+        Uses the search endpoint as a fast path. Only when the server doesn't offer search
+        (404/405) does it fall back to streaming every page (O(n) in the number of aliases),
+        stopping at the first match; any other error is raised.
+        """
+        try:
+            page_id = 0
+            while True:
+                page = await self.search_aliases(email, page_id=page_id)
+                if not page.aliases:
+                    return None
+                match = page.find_by_email(email)
+                if match:
+                    return match
+                page_id += 1
+        except ApiError as e:
+            if e.status not in (404, 405):
+                raise
+
+        target = email.casefold()
+        async for alias in self.iter_aliases():
+            if alias.email.casefold() == target:
+                return alias
+        return None
+
+    async def verify_alias_exists(self, alias_email: str) -> bool:
+        """
+        True if the alias can be looked up and is enabled. Meant to be polled (with retries)
+        right after creation, since self-hosted setups may not list a new alias immediately.
+        """
+        alias = await self.get_alias_by_email(alias_email)
+        return alias is not None and alias.enabled
 
     async def create_alias(
             self,
@@ -106,17 +474,105 @@ class AliasClient:
             response = await self.client.post(url, headers=headers, json=json_body)
 
             if response.status_code == 201:
-                return Alias(**response.json())
+                return parse_response(Alias, response)
             else:
-                raise Exception(f"Failed to create alias: {response.text}")
+                raise ApiError(response.status_code, response.text)
 
         except httpx.RequestError as e:
-            raise Exception(f"Network error occurred: {e}")
+            raise NetworkError(e) from e
+
+    async def get_alias_options(self, hostname: Optional[str] = None) -> AliasOptions:
+        """
+        Fetches the suffixes (and their signed versions) available for custom aliases.
+        """
+        if not self.api_key:
+            raise ValueError("API Key not set")
+
+        url = f"{self.base_url}/api/v5/alias/options"
+        headers = {"Authentication": self.api_key}
+        params = {"hostname": hostname} if hostname else {}
+
+        try:
+            response = await self.client.get(url, params=params, headers=headers)
+
+            if response.status_code == 200:
+                return parse_response(AliasOptions, response)
+            else:
+                raise ApiError(response.status_code, response.text)
+
+        except httpx.RequestError as e:
+            raise NetworkError(e) from e
+
+    async def list_available_domains(self, hostname: Optional[str] = None) -> List[AliasDomain]:
+        """
+        Domains the account can create custom aliases on, derived from the alias options
+        suffixes (each suffix ends in "@<domain>"). Order follows the API's suffix order.
+        """
+        options = await self.get_alias_options(hostname)
+        domains = {}
+        for suffix in options.suffixes:
+            domain = suffix.suffix.rpartition("@")[2]
+            if domain and domain not in domains:
+                domains[domain] = AliasDomain(domain=domain, is_premium=suffix.is_premium, is_custom=suffix.is_custom)
+        return list(domains.values())
+
+    async def create_custom_alias_with_suffix(
+            self,
+            alias_prefix: str,
+            suffix_index: int,
+            mailbox_ids: List[int],
+            note: Optional[str] = None,
+            name: Optional[str] = None,
+    ) -> Alias:
+        """
+        Fetches alias options and creates a custom alias using the suffix at `suffix_index`.
+        """
+        options = await self.get_alias_options()
+        if not options.can_create:
+            raise VaultError("Alias limit reached; no new aliases can be created")
+        if not 0 <= suffix_index < len(options.suffixes):
+            raise InvalidArgument(f"Suffix index {suffix_index} out of range ({len(options.suffixes)} available)")
+
+        suffix = options.suffixes[suffix_index]
+        return await self.create_alias(alias_prefix, suffix.signed_suffix, mailbox_ids, note=note, name=name)
+
+    async def create_alias_from_profile(self, profile: Profile, options: AliasOptions, mailbox_ids: List[int]) -> Alias:
+        """
+        Creates a custom alias named after the profile's domain ("bank.example.com" -> "bank-example"),
+        using the first suffix in `options`. Store the result with profile.link_alias(alias.id, alias.email).
+        """
+        prefix = alias_prefix_from_domain(profile.domain)
+        if not prefix:
+            raise InvalidArgument(f"Cannot derive an alias prefix from domain {profile.domain!r}")
+        if not options.can_create:
+            raise VaultError("Alias limit reached; no new aliases can be created")
+        if not options.suffixes:
+            raise InvalidArgument("Alias options contain no suffixes")
+
+        return await self.create_alias(
+            alias_prefix=prefix,
+            signed_suffix=options.suffixes[0].signed_suffix,
+            mailbox_ids=mailbox_ids,
+            note=f"Profile {profile.id}",
+            name=profile.title,
+        )
+
+    async def create_custom_alias_with_options(
+            self,
+            alias_prefix: str,
+            mailbox_ids: List[int],
+            note: Optional[str] = None,
+            name: Optional[str] = None,
+    ) -> Alias:
+        """
+        Creates a custom alias in one call, using the first available suffix.
+        """
+        return await self.create_custom_alias_with_suffix(alias_prefix, 0, mailbox_ids, note=note, name=name)
 
     async def create_random_alias(
             self,
             hostname: Optional[str] = None,
-            mode: Optional[str] = None,  # "uuid" or "word"
+            mode: Optional[str] = None,  # one of RANDOM_ALIAS_MODES
             note: Optional[str] = None,
     ) -> Alias:
         """
@@ -124,11 +580,13 @@ class AliasClient:
         """
         if not self.api_key:
             raise ValueError("API Key not set")
+        if mode is not None and mode not in RANDOM_ALIAS_MODES:
+            raise InvalidArgument(f"Invalid random alias mode {mode!r}; expected one of {', '.join(RANDOM_ALIAS_MODES)}")
 
         url = f"{self.base_url}/api/alias/random/new"
         headers = {"Authentication": self.api_key}
 
-        # Build query parameters
+        # Build query parameters (httpx URL-encodes them)
         params = {}
         if hostname:
             params["hostname"] = hostname
@@ -142,17 +600,243 @@ class AliasClient:
             response = await self.client.post(url, headers=headers, params=params, json=json_body)
 
             if response.status_code == 201:
-                return Alias(**response.json())
+                return parse_response(Alias, response)
+            else:
+                raise ApiError(response.status_code, response.text)
+
+        except httpx.RequestError as e:
+            raise NetworkError(e) from e
+
+    async def get_alias(self, alias_id: int) -> Alias:
+        """
+        Fetches a single alias by ID.
+        """
+        if not self.api_key:
+            raise ValueError("API Key not set")
+
+        url = f"{self.base_url}/api/aliases/{alias_id}"
+        headers = {"Authentication": self.api_key}
+
+        try:
+            response = await self.client.get(url, headers=headers)
+
+            if response.status_code == 200:
+                return parse_response(Alias, response)
             else:
-                raise Exception(f"Failed to create random alias: {response.text}")
+                raise ApiError(response.status_code, response.text)
+
+        except httpx.RequestError as e:
+            raise NetworkError(e) from e
+
+    async def update_alias(self, alias_id: int, update: UpdateAliasRequest):
+        """
+        Partially updates an alias (note, name, mailboxes, PGP, pinned).
+        """
+        if not self.api_key:
+            raise ValueError("API Key not set")
+
+        url = f"{self.base_url}/api/aliases/{alias_id}"
+        headers = {"Authentication": self.api_key}
+
+        try:
+            response = await self.client.patch(url, headers=headers, json=update.model_dump(exclude_none=True))
+
+            if response.status_code != 200:
+                raise ApiError(response.status_code, response.text)
 
         except httpx.RequestError as e:
-            raise Exception(f"Network error occurred: {e}")
+            raise NetworkError(e) from e
+
+    async def update_note(self, alias_id: int, note: str) -> Alias:
+        """
+        Sets only the alias note, leaving every other field untouched.
+        The PATCH response carries no alias, so the updated alias is fetched afterwards.
+        """
+        await self.update_alias(alias_id, UpdateAliasRequest(note=note))
+        return await self.get_alias(alias_id)
 
-    async def delete_alias(self, alias_id: int) -> bool:
+    async def change_alias_mailboxes(self, alias_id: int, new_mailbox_ids: List[int]) -> Alias:
+        """
+        Replaces the mailboxes an alias delivers to and returns the updated alias.
+        The list must be non-empty; the API rejects empty lists with an opaque error.
+        """
+        if not new_mailbox_ids:
+            raise InvalidArgument("An alias needs at least one mailbox")
+        await self.update_alias(alias_id, UpdateAliasRequest(mailbox_ids=list(new_mailbox_ids)))
+        return await self.get_alias(alias_id)
+
+    async def get_alias_stats(self, alias_id: int) -> AliasStats:
+        """
+        Activity counters from GET /api/aliases/{id}/stats. Deployments without that endpoint
+        (404) fall back to the counters embedded in the alias itself; raises NotSupported
+        when neither source has them.
+        """
+        if not self.api_key:
+            raise ValueError("API Key not set")
+
+        url = f"{self.base_url}/api/aliases/{alias_id}/stats"
+        headers = {"Authentication": self.api_key}
+
+        try:
+            response = await self.client.get(url, headers=headers)
+        except httpx.RequestError as e:
+            raise NetworkError(e) from e
+
+        if response.status_code == 200:
+            return parse_response(AliasStats, response)
+        if response.status_code != 404:
+            raise ApiError(response.status_code, response.text)
+
+        alias = await self.get_alias(alias_id)
+        if not {"nb_forward", "nb_reply", "nb_block"} & alias.model_fields_set:
+            raise NotSupported("Alias statistics are not available on this server")
+        return AliasStats(nb_forward=alias.nb_forward, nb_reply=alias.nb_reply, nb_block=alias.nb_block)
+
+    async def toggle_alias(self, alias_id: int) -> bool:
+        """
+        Flips an alias between enabled and disabled. Returns the new `enabled` state.
+        """
+        if not self.api_key:
+            raise ValueError("API Key not set")
+
+        url = f"{self.base_url}/api/aliases/{alias_id}/toggle"
+        headers = {"Authentication": self.api_key}
+
+        try:
+            response = await self.client.post(url, headers=headers)
+
+            if response.status_code == 200:
+                return parse_response(ToggleResponse, response).enabled
+            else:
+                raise ApiError(response.status_code, response.text)
+
+        except httpx.RequestError as e:
+            raise NetworkError(e) from e
+
+    async def set_alias_enabled(self, alias_id: int, enabled: bool) -> Alias:
+        """
+        Puts an alias in the requested state, toggling only if it differs.
+        """
+        alias = await self.get_alias(alias_id)
+        if alias.enabled != enabled:
+            new_state = await self.toggle_alias(alias_id)
+            alias = alias.model_copy(update={"enabled": new_state})
+        return alias
+
+    async def disable_alias(self, alias_id: int) -> Alias:
+        """
+        Disables an alias without deleting it (safe, reversible alternative to delete_alias).
+        """
+        return await self.set_alias_enabled(alias_id, False)
+
+    async def batch_toggle_aliases(self, alias_ids: List[int], enabled: bool, max_concurrent: int = 5) -> BatchToggleResult:
+        """
+        Enables or disables many aliases, running at most `max_concurrent` requests at once.
+        5 is the recommended limit to stay under SimpleLogin's rate limits.
+        Failures are collected per alias instead of aborting the batch.
+        """
+        semaphore = asyncio.Semaphore(max(1, max_concurrent))
+        result = BatchToggleResult()
+
+        async def worker(alias_id: int):
+            async with semaphore:
+                await asyncio.sleep(random_delay(BATCH_DELAY_MIN_MS, BATCH_DELAY_MAX_MS).total_seconds())
+                try:
+                    await self.set_alias_enabled(alias_id, enabled)
+                    result.succeeded.append(alias_id)
+                except Exception as e:
+                    result.failed.append((alias_id, e))
+
+        await asyncio.gather(*(worker(alias_id) for alias_id in alias_ids))
+        return result
+
+    async def list_contacts(self, alias_id: int, page_id: int = 0) -> ContactsResponse:
+        """
+        Fetches a page of contacts (people who emailed, or were emailed from) an alias.
+        """
+        if not self.api_key:
+            raise ValueError("API Key not set")
+
+        url = f"{self.base_url}/api/aliases/{alias_id}/contacts"
+        params = {"page_id": page_id}
+        headers = {"Authentication": self.api_key}
+
+        try:
+            response = await self.client.get(url, params=params, headers=headers)
+
+            if response.status_code == 200:
+                return parse_response(ContactsResponse, response)
+            else:
+                raise ApiError(response.status_code, response.text)
+
+        except httpx.RequestError as e:
+            raise NetworkError(e) from e
+
+    async def create_contact(self, alias_id: int, contact_email: str) -> Contact:
+        """
+        Creates a contact for an alias (returns the existing one if already present).
+        """
+        if not self.api_key:
+            raise ValueError("API Key not set")
+
+        url = f"{self.base_url}/api/aliases/{alias_id}/contacts"
+        headers = {"Authentication": self.api_key}
+
+        try:
+            response = await self.client.post(url, headers=headers, json={"contact": contact_email})
+
+            if response.status_code in (200, 201):
+                return parse_response(Contact, response)
+            else:
+                raise ApiError(response.status_code, response.text)
+
+        except httpx.RequestError as e:
+            raise NetworkError(e) from e
+
+    async def get_reverse_alias(self, alias_id: int, contact_email: str) -> str:
+        """
+        Returns the reverse alias to write to `contact_email` from this alias,
+        creating the contact first if it does not exist yet.
+        """
+        target = contact_email.casefold()
+        page_id = 0
+        while True:
+            page = await self.list_contacts(alias_id, page_id=page_id)
+            if not page.contacts:
+                break
+            for contact in page.contacts:
+                if contact.contact.casefold() == target:
+                    return contact.reverse_alias
+            page_id += 1
+
+        contact = await self.create_contact(alias_id, contact_email)
+        return contact.reverse_alias
+
+    async def verify_deliverability(self, alias_id: int) -> VerifyResult:
+        """
+        Checks that an alias can forward mail: it is enabled and has at least one mailbox.
+
+        NOTE: this only inspects the alias state; it does not prove mail is delivered.
+        SimpleLogin has no endpoint for sending a test message, so `test_email_sent`
+        is always False.
+        """
+        alias = await self.get_alias(alias_id)
+        return VerifyResult(
+            test_email_sent=False,
+            alias_active=alias.enabled and len(alias.mailboxes) > 0,
+        )
+
+    async def delete_alias(self, alias_id: int, trash: bool = False) -> bool:
         """
         Deletes an alias by ID.
+
+        WARNING: SimpleLogin deletion is permanent and cannot be undone.
+        Pass trash=True to only disable the alias instead.
         """
+        if trash:
+            await self.disable_alias(alias_id)
+            return True
+
         if not self.api_key:
             raise ValueError("API Key not set")
 
@@ -165,10 +849,10 @@ class AliasClient:
             if response.status_code == 200:
                 return True
             else:
-                raise Exception(f"Failed to delete alias: {response.text}")
+                raise ApiError(response.status_code, response.text)
 
         except httpx.RequestError as e:
-            raise Exception(f"Network error occurred: {e}")
+            raise NetworkError(e) from e
 
     async def close(self):
         """Closes the underlying HTTP client."""
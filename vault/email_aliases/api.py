@@ -1,3 +1,7 @@
+import time
+import warnings
+from datetime import timedelta
+
 import httpx
 from typing import Optional
 
@@ -5,20 +9,48 @@ from email_aliases.aliases import AliasClient
 from email_aliases.mailboxes import MailboxClient
 from email_aliases.user import UserClient
 from email_aliases.auth import AuthClient
+from email_aliases.http import DEFAULT_MAX_RESPONSE_BODY_BYTES, HttpClient, Retry502Transport, SizeLimitedTransport
+from config import DEFAULT_SL_BASE_URL, Config
+from vault.errors import InvalidArgument, MfaRequired, NetworkError, NotFound, Unauthorized, VaultError
 
 
 class SimpleLoginClient:
-    def __init__(self, api_key: Optional[str] = None, base_url: str = "https://api.simplelogin.io"):
+    def __init__(
+            self,
+            api_key: Optional[str] = None,
+            base_url: str = "https://api.simplelogin.io",
+            max_response_body_bytes: int = DEFAULT_MAX_RESPONSE_BODY_BYTES,
+            http_client: Optional[HttpClient] = None,
+            danger_accept_invalid_certs: bool = False,
+            reconnect_on_502: bool = False,
+    ):
         """
         Initializes the main client which acts as a wrapper for all sub-clients.
 
         :param api_key: The API key for authenticated endpoints.
         :param base_url: The API base URL (defaults to official SimpleLogin API).
+        :param max_response_body_bytes: Responses larger than this raise ResponseTooLarge.
+        :param http_client: Optional preconfigured HTTP client (e.g. with logging, metrics or
+                            retry middleware). It is used as-is, so max_response_body_bytes does
+                            not apply, and the caller remains responsible for closing it.
+        :param danger_accept_invalid_certs: Skip TLS certificate verification, for self-hosted
+                            development instances with self-signed certificates only.
+                            NEVER enable this in production. Ignored when http_client is given.
+        :param reconnect_on_502: Retry a request once after 2 seconds when it gets 502 Bad Gateway,
+                            as self-hosted instances behind nginx do while restarting.
+                            Ignored when http_client is given.
         """
         self.base_url = base_url.rstrip("/")
+        # kept so reconnect() can rebuild the same client
+        self._max_response_body_bytes = max_response_body_bytes
+        self._danger_accept_invalid_certs = danger_accept_invalid_certs
+        self._reconnect_on_502 = reconnect_on_502
 
         # We create a single shared httpx Client for connection pooling
-        self.client = httpx.AsyncClient()
+        self._owns_client = http_client is None
+        if danger_accept_invalid_certs and http_client is None:
+            warnings.warn("TLS certificate verification is disabled for SimpleLoginClient", stacklevel=2)
+        self.client = http_client or self._build_http_client()
 
         # Initialize sub-clients with the shared http client
         self.auth = AuthClient(base_url=self.base_url, client=self.client)
@@ -41,9 +73,122 @@ class SimpleLoginClient:
             client=self.client
         )
 
+    def _build_http_client(self) -> httpx.AsyncClient:
+        transport = httpx.AsyncHTTPTransport(verify=not self._danger_accept_invalid_certs)
+        if self._reconnect_on_502:
+            transport = Retry502Transport(transport)
+        return httpx.AsyncClient(transport=SizeLimitedTransport(self._max_response_body_bytes, transport=transport))
+
+    async def reconnect(self):
+        """
+        Replaces the shared HTTP client (and its connection pool) with a fresh one built from
+        the original settings, e.g. after connections went stale in a long-running process.
+        Not possible for a caller-supplied http_client.
+        """
+        if not self._owns_client:
+            raise VaultError("Cannot reconnect a caller-supplied HTTP client")
+        old = self.client
+        self.client = self._build_http_client()
+        for sub in (self.auth, self.user, self.aliases, self.mailboxes):
+            sub.client = self.client
+        await old.aclose()
+
+    async def ping(self) -> timedelta:
+        """
+        Round-trip time of a request to the API base URL. Any HTTP response counts as reachable;
+        raises NetworkError when the server can't be reached.
+        """
+        start = time.perf_counter()
+        try:
+            await self.client.get(f"{self.base_url}/")
+        except httpx.RequestError as e:
+            raise NetworkError(e) from e
+        return timedelta(seconds=time.perf_counter() - start)
+
+    @classmethod
+    def from_config(cls, config: Config, account: Optional[str] = None) -> "SimpleLoginClient":
+        """
+        Creates a client for the named account, or the default account when `account` is None.
+        """
+        if account is None:
+            acc = config.default_account()
+        else:
+            acc = config.account(account)
+            if acc is None:
+                raise NotFound(f"Unknown SimpleLogin account: {account}")
+        return cls(api_key=acc.api_key, base_url=acc.base_url)
+
+    @property
+    def api_key(self) -> Optional[str]:
+        """The API key used by the authenticated sub-clients, if any."""
+        return self.aliases.api_key
+
+    @property
+    def authenticated(self) -> bool:
+        """True when an API key is set (not whether the server accepts it)."""
+        return bool(self.api_key)
+
+    def swap_api_key(self, new_key: str):
+        """
+        Switches every authenticated sub-client to `new_key` in place, e.g. after
+        key rotation via user.create_api_key. The auth client takes no stored key.
+        """
+        for sub in (self.user, self.aliases, self.mailboxes):
+            sub.set_api_key(new_key)
+
+    def with_api_key(self, key: str) -> "SimpleLoginClient":
+        """
+        Upgrades this (e.g. unauthenticated) client in place, typically with the key returned
+        by auth.login, keeping the same HTTP client and configuration. Returns self for chaining.
+        """
+        self.swap_api_key(key)
+        return self
+
+    @classmethod
+    def from_env(cls) -> "SimpleLoginClient":
+        """
+        Client for the default account configured in the environment (SL_API_KEY, or
+        SL_ACCOUNTS; see config.py). No login request is made.
+        """
+        config = Config.from_env()
+        try:
+            acc = config.default_account()
+        except NotFound:
+            acc = None
+        if acc is None or not acc.api_key:
+            raise InvalidArgument("No SimpleLogin API key configured (set SL_API_KEY)")
+        return cls(api_key=acc.api_key, base_url=acc.base_url)
+
+    @classmethod
+    async def login_from_env(cls) -> "SimpleLoginClient":
+        """
+        Logs in with SL_EMAIL / SL_PASSWORD / SL_DEVICE and returns an authenticated client.
+        Raises MfaRequired when the account has MFA enabled.
+        """
+        config = Config.from_env()
+        if not (config.sl_email and config.sl_password and config.sl_device):
+            raise InvalidArgument("SL_EMAIL, SL_PASSWORD and SL_DEVICE must be set")
+        try:
+            base_url = config.default_account().base_url
+        except NotFound:
+            base_url = DEFAULT_SL_BASE_URL
+
+        client = cls(base_url=base_url)
+        try:
+            resp = await client.auth.login(config.sl_email, config.sl_password, config.sl_device)
+            if resp.mfa_enabled:
+                raise MfaRequired(resp.mfa_key)
+            if not resp.api_key:
+                raise Unauthorized("Login succeeded but no API key was returned")
+        except BaseException:
+            await client.close()
+            raise
+        return client.with_api_key(resp.api_key)
+
     async def close(self):
-        """Closes the underlying shared HTTP client."""
-        await self.client.aclose()
+        """Closes the underlying shared HTTP client (unless it was supplied by the caller)."""
+        if self._owns_client:
+            await self.client.aclose()
 
     async def __aenter__(self):
         return self
@@ -2,6 +2,9 @@ import httpx
 from pydantic import BaseModel
 from typing import Optional
 
+from email_aliases.http import HttpClient, parse_response
+from vault.errors import ApiError, InvalidArgument, NetworkError, Unauthorized
+
 
 # ---------------------------------------------------------------------------
 # Data Models (Serde equivalents)
@@ -36,11 +39,11 @@ class RegisterRequest(BaseModel):
 # ---------------------------------------------------------------------------
 
 class AuthClient:
-    def __init__(self, base_url: str, client: Optional[httpx.AsyncClient] = None):
+    def __init__(self, base_url: str, client: Optional[HttpClient] = None):
         """
         :param base_url: The API base URL.
-        :param client: Optional existing httpx.AsyncClient. If None, one is created per request
-                       (or you can manage the lifecycle externally).
+        :param client: Optional existing HTTP client (httpx.AsyncClient or compatible).
+                       If None, one is created (or you can manage the lifecycle externally).
         """
         self.base_url = base_url.rstrip("/")
         # In Python, we usually pass the client in or manage it via a context manager.
@@ -56,17 +59,17 @@ class AuthClient:
 
             if response.status_code == 200:
                 # Parse JSON into Pydantic model
-                return LoginResponse(**response.json())
+                return parse_response(LoginResponse, response)
 
             elif response.status_code == 403:
-                raise Exception("FIDO enabled, use API Key instead")
+                raise Unauthorized("FIDO enabled, use API Key instead")
 
             else:
                 # Capture text from error response
-                raise Exception(f"Login failed: {response.text}")
+                raise ApiError(response.status_code, response.text)
 
         except httpx.RequestError as e:
-            raise Exception(f"Network error occurred: {e}")
+            raise NetworkError(e) from e
 
     async def register(self, email: str, password: str) -> None:
         url = f"{self.base_url}/api/auth/register"
@@ -78,10 +81,10 @@ class AuthClient:
             if response.is_success:
                 return None
             else:
-                raise Exception(f"Registration failed: {response.text}")
+                raise ApiError(response.status_code, response.text)
 
         except httpx.RequestError as e:
-            raise Exception(f"Network error occurred: {e}")
+            raise NetworkError(e) from e
 
     async def activate(self, email: str, code: str) -> None:
         url = f"{self.base_url}/api/auth/activate"
@@ -94,16 +97,41 @@ class AuthClient:
                 return None
 
             elif response.status_code == 400:
-                raise Exception("Wrong email or code")
+                raise Unauthorized("Wrong email or code")
 
             elif response.status_code == 410:  # HTTP 410 Gone
-                raise Exception("Too many failed attempts. Request reactivation")
+                raise Unauthorized("Too many failed attempts. Request reactivation")
+
+            else:
+                raise ApiError(response.status_code, response.text)
+
+        except httpx.RequestError as e:
+            raise NetworkError(e) from e
+
+    async def verify_api_key(self, api_key: str) -> bool:
+        """
+        True if the server accepts `api_key`, False if it rejects it (401). Other failures
+        (network, 5xx, unexpected responses) raise, so "invalid" is never confused with "unreachable".
+        """
+        if not api_key:
+            raise InvalidArgument("API key must not be empty")
+        url = f"{self.base_url}/api/user_info"
+        headers = {"Authentication": api_key}
+
+        try:
+            response = await self.client.get(url, headers=headers)
+
+            if response.status_code == 200:
+                return True
+
+            elif response.status_code == 401:
+                return False
 
             else:
-                raise Exception(f"Activation failed: {response.text}")
+                raise ApiError(response.status_code, response.text)
 
         except httpx.RequestError as e:
-            raise Exception(f"Network error occurred: {e}")
+            raise NetworkError(e) from e
 
     async def close(self):
         """Closes the underlying HTTP client."""
@@ -0,0 +1,160 @@
+import asyncio
+import json
+from dataclasses import dataclass
+
+import httpx
+from pydantic import BaseModel, ValidationError
+from typing import Any, AsyncIterator, Generic, Optional, Protocol, Type, TypeVar, Union
+
+from vault.errors import DeserializationError, ResponseTooLarge, VaultError
+
+M = TypeVar("M", bound=BaseModel)
+
+DEFAULT_MAX_RESPONSE_BODY_BYTES = 1024 * 1024  # 1 MiB
+BAD_GATEWAY_RETRY_DELAY_SECONDS = 2.0
+
+
+# ---------------------------------------------------------------------------
+# HTTP client interface
+# ---------------------------------------------------------------------------
+
+class HttpClient(Protocol):
+    """
+    What the sub-clients need from an HTTP client. httpx.AsyncClient satisfies it,
+    as does any wrapper adding logging, metrics or retries with the same methods.
+    """
+
+    async def get(self, url: str, **kwargs: Any) -> httpx.Response: ...
+
+    async def post(self, url: str, **kwargs: Any) -> httpx.Response: ...
+
+    async def patch(self, url: str, **kwargs: Any) -> httpx.Response: ...
+
+    async def delete(self, url: str, **kwargs: Any) -> httpx.Response: ...
+
+    async def aclose(self) -> None: ...
+
+
+# ---------------------------------------------------------------------------
+# Response size limiting
+# ---------------------------------------------------------------------------
+
+class _LimitedStream(httpx.AsyncByteStream):
+    """Wraps a response stream and aborts once more than `max_bytes` have been read."""
+
+    def __init__(self, stream: httpx.AsyncByteStream, max_bytes: int):
+        self._stream = stream
+        self._max_bytes = max_bytes
+
+    async def __aiter__(self) -> AsyncIterator[bytes]:
+        total = 0
+        async for chunk in self._stream:
+            total += len(chunk)
+            if total > self._max_bytes:
+                raise ResponseTooLarge(actual_bytes=total, max_bytes=self._max_bytes)
+            yield chunk
+
+    async def aclose(self):
+        await self._stream.aclose()
+
+
+class SizeLimitedTransport(httpx.AsyncBaseTransport):
+    """
+    Transport that refuses response bodies larger than `max_bytes`.
+
+    Checks Content-Length up front, then counts bytes while streaming so that
+    servers lying about (or omitting) the length are caught before the body is buffered.
+    Compression is refused (Accept-Encoding: identity, and encoded responses are rejected),
+    so a small gzip body can't inflate past the limit when httpx decodes it: the bytes
+    counted on the wire are exactly the bytes that end up in memory.
+    """
+
+    def __init__(self, max_bytes: int = DEFAULT_MAX_RESPONSE_BODY_BYTES, transport: Optional[httpx.AsyncBaseTransport] = None):
+        self.max_bytes = max_bytes
+        self._transport = transport or httpx.AsyncHTTPTransport()
+
+    async def handle_async_request(self, request: httpx.Request) -> httpx.Response:
+        request.headers["Accept-Encoding"] = "identity"
+        response = await self._transport.handle_async_request(request)
+
+        encoding = response.headers.get("content-encoding", "identity").strip().lower()
+        if encoding not in ("", "identity"):
+            await response.aclose()
+            raise VaultError(f"Server sent a {encoding}-encoded response although only identity was accepted")
+
+        content_length = response.headers.get("content-length")
+        if content_length is not None and content_length.isdigit() and int(content_length) > self.max_bytes:
+            await response.aclose()
+            raise ResponseTooLarge(actual_bytes=int(content_length), max_bytes=self.max_bytes)
+
+        return httpx.Response(
+            status_code=response.status_code,
+            headers=response.headers,
+            stream=_LimitedStream(response.stream, self.max_bytes),
+            extensions=response.extensions,
+        )
+
+    async def aclose(self):
+        await self._transport.aclose()
+
+
+# ---------------------------------------------------------------------------
+# Transient 502s
+# ---------------------------------------------------------------------------
+
+class Retry502Transport(httpx.AsyncBaseTransport):
+    """
+    Transport that retries a request once, after a fixed delay, when it gets 502 Bad Gateway.
+
+    Meant for self-hosted SimpleLogin behind a reverse proxy, which answers 502 while the
+    app restarts; that usually clears within seconds, so a single short wait is enough.
+    A second 502 is returned to the caller unchanged.
+    """
+
+    def __init__(self, transport: Optional[httpx.AsyncBaseTransport] = None, delay_seconds: float = BAD_GATEWAY_RETRY_DELAY_SECONDS):
+        self.delay_seconds = delay_seconds
+        self._transport = transport or httpx.AsyncHTTPTransport()
+
+    async def handle_async_request(self, request: httpx.Request) -> httpx.Response:
+        response = await self._transport.handle_async_request(request)
+        if response.status_code != 502:
+            return response
+        await response.aclose()
+        await asyncio.sleep(self.delay_seconds)
+        return await self._transport.handle_async_request(request)
+
+    async def aclose(self):
+        await self._transport.aclose()
+
+
+# ---------------------------------------------------------------------------
+# Conditional requests (ETag / If-None-Match)
+# ---------------------------------------------------------------------------
+
+@dataclass
+class NotModified:
+    """304: the cached copy for the sent ETag is still current."""
+
+
+@dataclass
+class Modified(Generic[M]):
+    value: M
+    etag: Optional[str] = None  # pass to the next call, if the server sent one
+
+
+ConditionalResponse = Union[NotModified, Modified[M]]
+
+
+# ---------------------------------------------------------------------------
+# Response parsing
+# ---------------------------------------------------------------------------
+
+def parse_response(model: Type[M], response: httpx.Response) -> M:
+    """
+    Parses a JSON response into `model`. On failure raises DeserializationError
+    carrying the raw body (first 2 KB), which makes API version mismatches debuggable.
+    """
+    try:
+        return model(**response.json())
+    except (json.JSONDecodeError, ValidationError, TypeError) as e:
+        raise DeserializationError(f"{model.__name__}: {e}", response.text) from e
@@ -1,6 +1,12 @@
+from dataclasses import dataclass, field
+
 import httpx
 from pydantic import BaseModel
-from typing import List, Optional
+from typing import Iterator, List, Optional, Tuple
+
+from email_aliases.aliases import AliasClient, UpdateAliasRequest
+from email_aliases.http import HttpClient, parse_response
+from vault.errors import ApiError, NetworkError, NotFound
 
 
 # ---------------------------------------------------------------------------
@@ -15,27 +21,62 @@ class Mailbox(BaseModel):
     nb_alias: int
     verified: bool
 
+    @property
+    def domain(self) -> str:
+        """Everything after the last '@' of the mailbox email."""
+        return self.email.rpartition("@")[2]
+
+    @property
+    def local_part(self) -> str:
+        """Everything before the last '@' of the mailbox email."""
+        return self.email.rpartition("@")[0]
+
 
 class MailboxesResponse(BaseModel):
     mailboxes: List[Mailbox]
 
+    # Iterate over the mailboxes directly: `for box in response: ...`
+    # (replaces pydantic's default field iteration).
+    def __iter__(self) -> Iterator[Mailbox]:
+        return iter(self.mailboxes)
+
+    def __len__(self) -> int:
+        return len(self.mailboxes)
+
+    def find_by_email(self, email: str) -> Optional[Mailbox]:
+        """Case-insensitive lookup by mailbox email."""
+        target = email.casefold()
+        return next((m for m in self.mailboxes if m.email.casefold() == target), None)
+
+    def find_by_id(self, mailbox_id: int) -> Optional[Mailbox]:
+        return next((m for m in self.mailboxes if m.id == mailbox_id), None)
+
+
+@dataclass
+class TransferReport:
+    transferred: int = 0
+    failed: List[Tuple[int, Exception]] = field(default_factory=list)
+
 
 # ---------------------------------------------------------------------------
 # Client Implementation
 # ---------------------------------------------------------------------------
 
 class MailboxClient:
-    def __init__(self, base_url: str, api_key: Optional[str] = None, client: Optional[httpx.AsyncClient] = None):
+    def __init__(self, base_url: str, api_key: Optional[str] = None, client: Optional[HttpClient] = None):
         """
         :param base_url: The API base URL.
         :param api_key: Optional API key for authentication.
-        :param client: Optional existing httpx.AsyncClient.
+        :param client: Optional existing HTTP client (httpx.AsyncClient or compatible).
         """
         self.base_url = base_url.rstrip("/")
         self.api_key = api_key
         # Use provided client or create a new one
         self.client = client if client else httpx.AsyncClient()
 
+    def set_api_key(self, api_key: str):
+        self.api_key = api_key
+
     async def list_mailboxes(self) -> MailboxesResponse:
         """
         Fetches the list of mailboxes.
@@ -55,12 +96,55 @@ class MailboxClient:
             response = await self.client.get(url, headers=headers)
 
             if response.is_success:
-                return MailboxesResponse(**response.json())
+                return parse_response(MailboxesResponse, response)
             else:
-                raise Exception(f"Failed to list mailboxes: {response.text}")
+                raise ApiError(response.status_code, response.text)
 
         except httpx.RequestError as e:
-            raise Exception(f"Network error occurred: {e}")
+            raise NetworkError(e) from e
+
+    async def get_mailbox(self, mailbox_id: int) -> Mailbox:
+        """
+        Fetches a single mailbox by ID (the API only exposes the full list).
+        """
+        mailbox = (await self.list_mailboxes()).find_by_id(mailbox_id)
+        if mailbox is None:
+            raise NotFound("Mailbox not found")
+        return mailbox
+
+    async def get_default_mailbox(self) -> Mailbox:
+        """
+        The mailbox flagged `default`. The API always has one; NotFound is purely defensive.
+        """
+        mailbox = next((m for m in await self.list_mailboxes() if m.default), None)
+        if mailbox is None:
+            raise NotFound("No default mailbox")
+        return mailbox
+
+    async def get_alias_count(self, mailbox_id: int) -> int:
+        """
+        Number of aliases delivering to the mailbox, without listing the aliases themselves.
+        """
+        return (await self.get_mailbox(mailbox_id)).nb_alias
+
+    async def transfer_aliases_between_mailboxes(self, from_mailbox_id: int, to_mailbox_id: int, alias_client: AliasClient) -> TransferReport:
+        """
+        Moves every alias delivering to `from_mailbox_id` over to `to_mailbox_id`.
+        Other mailboxes on an alias are kept. Failures are collected per alias instead of aborting.
+        """
+        # collect first so updates don't interfere with pagination
+        aliases = [a async for a in alias_client.iter_aliases() if any(m.id == from_mailbox_id for m in a.mailboxes)]
+
+        report = TransferReport()
+        for alias in aliases:
+            mailbox_ids = [m.id for m in alias.mailboxes if m.id not in (from_mailbox_id, to_mailbox_id)]
+            mailbox_ids.append(to_mailbox_id)
+            try:
+                await alias_client.update_alias(alias.id, UpdateAliasRequest(mailbox_ids=mailbox_ids))
+                report.transferred += 1
+            except Exception as e:
+                report.failed.append((alias.id, e))
+        return report
 
     async def close(self):
         """Closes the underlying HTTP client."""
@@ -86,7 +170,7 @@ if __name__ == "__main__":
             try:
                 # Fetch mailboxes
                 response = await mb_client.list_mailboxes()
-                for box in response.mailboxes:
+                for box in response:
                     print(f"Mailbox: {box.email} (Verified: {box.verified})")
             except Exception as e:
                 print(f"Error: {e}")
@@ -3,6 +3,9 @@ from typing import Optional
 import httpx
 from pydantic import BaseModel
 
+from email_aliases.http import HttpClient, parse_response
+from vault.errors import ApiError, NetworkError, Unauthorized
+
 
 # ---------------------------------------------------------------------------
 # Data Models
@@ -15,6 +18,21 @@ class UserInfo(BaseModel):
     in_trial: bool
     profile_picture_url: Optional[str] = None
     max_alias_free_plan: Optional[int] = None
+    # Newer API versions only; None when the server omits them.
+    alias_count: Optional[int] = None
+    mailbox_count: Optional[int] = None
+    connected_domains: Optional[int] = None
+
+    def is_over_alias_limit(self) -> Optional[bool]:
+        """
+        True when the free-plan alias quota is used up; None if either count is unknown.
+        Premium accounts are never over the limit.
+        """
+        if self.is_premium:
+            return False
+        if self.alias_count is None or self.max_alias_free_plan is None:
+            return None
+        return self.alias_count >= self.max_alias_free_plan
 
 
 class ApiKeyRequest(BaseModel):
@@ -30,16 +48,19 @@ class ApiKeyResponse(BaseModel):
 # ---------------------------------------------------------------------------
 
 class UserClient:
-    def __init__(self, base_url: str, api_key: Optional[str] = None, client: Optional[httpx.AsyncClient] = None):
+    def __init__(self, base_url: str, api_key: Optional[str] = None, client: Optional[HttpClient] = None):
         """
         :param base_url: The API base URL.
         :param api_key: Optional API key for authentication.
-        :param client: Optional existing httpx.AsyncClient.
+        :param client: Optional existing HTTP client (httpx.AsyncClient or compatible).
         """
         self.base_url = base_url.rstrip("/")
         self.api_key = api_key
         self.client = client if client else httpx.AsyncClient()
 
+    def set_api_key(self, api_key: str):
+        self.api_key = api_key
+
     async def get_user_info(self) -> UserInfo:
         """
         Fetches the current user's information.
@@ -54,14 +75,14 @@ class UserClient:
             response = await self.client.get(url, headers=headers)
 
             if response.status_code == 200:
-                return UserInfo(**response.json())
+                return parse_response(UserInfo, response)
             elif response.status_code == 401:
-                raise Exception("Invalid API Key")
+                raise Unauthorized("Invalid API Key")
             else:
-                raise Exception(f"Failed to fetch user info: {response.text}")
+                raise ApiError(response.status_code, response.text)
 
         except httpx.RequestError as e:
-            raise Exception(f"Network error occurred: {e}")
+            raise NetworkError(e) from e
 
     async def create_api_key(self, login_api_key: str, device: str) -> ApiKeyResponse:
         """
@@ -76,14 +97,14 @@ class UserClient:
             response = await self.client.post(url, headers=headers, json=payload.model_dump())
 
             if response.status_code == 201:  # Matches reqwest::StatusCode::CREATED
-                return ApiKeyResponse(**response.json())
+                return parse_response(ApiKeyResponse, response)
             elif response.status_code == 401:
-                raise Exception("Unauthorized")
+                raise Unauthorized("Unauthorized")
             else:
-                raise Exception(f"Failed to create API Key: {response.text}")
+                raise ApiError(response.status_code, response.text)
 
         except httpx.RequestError as e:
-            raise Exception(f"Network error occurred: {e}")
+            raise NetworkError(e) from e
 
     async def close(self):
         """Closes the underlying HTTP client."""
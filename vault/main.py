@@ -1,46 +1,19 @@
 import asyncio
-import os
-
-from dotenv import load_dotenv
 
 from email_aliases.api import SimpleLoginClient
 
 
 async def main():
-    # Load environment variables from .env file
-    load_dotenv()
-
-    async with SimpleLoginClient(api_key=None) as client:
-        #email = os.getenv("SL_EMAIL")
-        #password = os.getenv("SL_PASSWORD")
-        #device = os.getenv("SL_DEVICE")
-#
-        #if not all([email, password, device]):
-        #    print("Error: SL_EMAIL, SL_PASSWORD, and SL_DEVICE must be set in .env")
-        #    return
-#
-        #print(f"Attempting login for {email}...")
-#
-        #try:
-        #    login_resp = await client.auth.login(email, password, device)
-        #except Exception as e:
-        #    print(f"Login failed: {e}")
-        #    return
-#
-        #if login_resp.mfa_enabled:
-        #    print(f"MFA is enabled. Use MFA key: {login_resp.mfa_key or 'None'}")
-        #    return  # Stop here as per original Rust logic
-#
-        #if not login_resp.api_key:
-        #    print("Error: Login successful but no API key returned.")
-        #    return
-
-        #api_key = login_resp.api_key
-        api_key = os.getenv("SL_API_KEY")
-        print(f"Logged in! API Key: {api_key}")
-
-    # We start a new session now that we have the key
-    async with SimpleLoginClient(api_key=api_key) as client:
+    # Reads SL_API_KEY (or SL_ACCOUNTS) from the environment / .env.
+    # To log in with SL_EMAIL, SL_PASSWORD and SL_DEVICE instead:
+    #     client = await SimpleLoginClient.login_from_env()
+    try:
+        client = SimpleLoginClient.from_env()
+    except Exception as e:
+        print(f"Error: {e}")
+        return
+
+    async with client:
 
         # 5️⃣ Get alias options
         try:
@@ -1,10 +1,40 @@
+import colorsys
+import hashlib
+import ipaddress
 import random
+import secrets
+from typing import Optional, Tuple
+from uuid import UUID
 
+from profile.models import PII, Address, Profile
+from profile.types import IpRegion, SiteType
+from utils.random import pick
 
-def generate_username() -> str:
+_WHITE_COLLAR_OCCUPATIONS = (
+    "Accountant", "Financial Analyst", "Actuary", "Attorney", "Auditor", "Consultant",
+    "Project Manager", "Software Engineer", "Civil Engineer", "Pharmacist", "Architect",
+    "Operations Manager", "Compliance Officer", "Data Analyst", "Teacher", "Physician",
+)
+
+_GENERAL_OCCUPATIONS = _WHITE_COLLAR_OCCUPATIONS + (
+    "Barista", "Graphic Designer", "Electrician", "Chef", "Musician", "Photographer",
+    "Student", "Nurse", "Carpenter", "Retail Associate", "Video Editor", "Mechanic",
+    "Bartender", "Freelance Writer", "Delivery Driver", "Fitness Instructor",
+)
+
+# Sites where a conventional, steady profession looks most plausible
+_WHITE_COLLAR_SITES = {
+    SiteType.BANK, SiteType.INSURANCE, SiteType.GOVERNMENT, SiteType.PROFESSIONAL,
+    SiteType.HEALTHCARE, SiteType.CRYPTOCURRENCY,
+}
+
+
+def generate_username(rng: Optional[random.Random] = None) -> str:
     """
     Generates a random username using various formats (CamelCase, snake_case, etc.).
+    Pass `rng` (e.g. Profile.consistent_seed_rng("username")) to make the result reproducible.
     """
+    rng = rng or random.Random()
     adjectives = [
         "Ancient", "Bright", "Curious", "Dizzy", "Electric", "Fuzzy",
         "Gentle", "Hidden", "Jolly", "Kind", "Lucky", "Mighty", "Noisy",
@@ -39,18 +69,34 @@ def generate_username() -> str:
     ]
 
     # Select random components
-    adj = random.choice(adjectives)
-    noun = random.choice(nouns)
+    adj = rng.choice(adjectives)
+    noun = rng.choice(nouns)
     # Rust 10..9999 is exclusive of the upper bound.
     # Python randint is inclusive, so we use 9998.
-    number = random.randint(10, 9998)
-    suffix = random.choice(suffixes)
+    number = rng.randint(10, 9998)
+    suffix = rng.choice(suffixes)
 
     # Select a random format function and execute it
-    formatter = random.choice(formats)
+    formatter = rng.choice(formats)
     return formatter(adj, noun, number, suffix)
 
 
+def generate_username_seeded(seed: int) -> str:
+    """
+    Same seed -> same username, so a lost profile's username can be regenerated
+    from a stored seed (or one derived from the profile ID).
+    """
+    return generate_username(random.Random(seed))
+
+
+def generate_username_with_hint(seed: str) -> str:
+    """
+    generate_username() plus 4 hex chars of sha256(seed), e.g. the profile UUID or site domain,
+    so different seeds lower the odds of hitting an already-taken name. Not a uniqueness guarantee.
+    """
+    return generate_username() + hashlib.sha256(seed.encode("utf-8")).digest()[:2].hex()
+
+
 def generate_first_name() -> str:
     first_names = [
         "Lena", "Kai", "Nova", "Arlo", "Sasha", "Ezra", "Rhea", "Juno", "Milo", "Niko",
@@ -69,4 +115,207 @@ def generate_last_name() -> str:
 
 
 def generate_full_name() -> str:
-    return f"{generate_first_name()} {generate_last_name()}"
\ No newline at end of file
+    return f"{generate_first_name()} {generate_last_name()}"
+
+
+def generate_occupation(site_type: SiteType) -> str:
+    """
+    Picks an occupation that fits the site: white-collar professions for banks,
+    insurers and similar; a wider range everywhere else.
+    """
+    if site_type in _WHITE_COLLAR_SITES:
+        return pick(_WHITE_COLLAR_OCCUPATIONS)
+    return pick(_GENERAL_OCCUPATIONS)
+
+
+_HOBBIES = (
+    "hiking", "photography", "chess", "baking", "cycling", "gardening", "board games",
+    "jazz", "bouldering", "birdwatching", "sci-fi novels", "running", "woodworking", "travel",
+)
+
+# Bio templates by tone; filled with first name, occupation (lowercase, optionally with
+# its article) and two hobbies
+_BIO_TEMPLATES = {
+    "professional": (
+        "{name} is {a_occupation} who enjoys solving hard problems. Outside of work: {hobby1} and {hobby2}.",
+        "{occupation_title} by trade, tinkerer at heart. {name} spends free time on {hobby1}.",
+    ),
+    "formal": (
+        "{name} is an experienced {occupation} with an interest in {hobby1} and {hobby2}.",
+        "{occupation_title} focused on quality and reliable results. Interests include {hobby1}.",
+    ),
+    "casual": (
+        "Hey, I'm {name}! Mostly here for fun, otherwise into {hobby1} and {hobby2}.",
+        "{name} here. {occupation_title} by day, {hobby1} fan by night.",
+    ),
+    "neutral": (
+        "{name}, {occupation}. Enjoys {hobby1} and {hobby2}.",
+        "{occupation_title} who likes {hobby1} and the occasional bit of {hobby2}.",
+    ),
+}
+
+_BIO_TONES = {
+    SiteType.DEVELOPER_TOOLS: "professional",
+    SiteType.PROFESSIONAL: "formal",
+    SiteType.BANK: "formal",
+    SiteType.INSURANCE: "formal",
+    SiteType.GAMING: "casual",
+    SiteType.SOCIAL_MEDIA: "casual",
+    SiteType.FORUM: "casual",
+    SiteType.ENTERTAINMENT: "casual",
+}
+
+
+def generate_bio(pii: PII, site_type: SiteType) -> str:
+    """
+    1-2 sentence bio from the first name, occupation and random hobbies, in a tone suited
+    to the site. Only uses name and occupation from `pii`, never documents or contact details.
+    """
+    occupation = pii.occupation or generate_occupation(site_type)
+    hobby1, hobby2 = secrets.SystemRandom().sample(_HOBBIES, 2)
+    template = pick(_BIO_TEMPLATES[_BIO_TONES.get(site_type, "neutral")])
+    return template.format(
+        name=pii.first_name or generate_first_name(),
+        occupation=occupation.lower(),
+        a_occupation=("an " if occupation[:1].lower() in "aeiou" else "a ") + occupation.lower(),
+        occupation_title=occupation,
+        hobby1=hobby1,
+        hobby2=hobby2,
+    )
+
+
+# US state abbreviation -> IANA zone (the zone covering most of the state's population)
+_US_STATE_TIMEZONES = {
+    "AL": "America/Chicago", "AK": "America/Anchorage", "AZ": "America/Phoenix", "AR": "America/Chicago",
+    "CA": "America/Los_Angeles", "CO": "America/Denver", "CT": "America/New_York", "DE": "America/New_York",
+    "DC": "America/New_York", "FL": "America/New_York", "GA": "America/New_York", "HI": "Pacific/Honolulu",
+    "ID": "America/Boise", "IL": "America/Chicago", "IN": "America/Indiana/Indianapolis", "IA": "America/Chicago",
+    "KS": "America/Chicago", "KY": "America/New_York", "LA": "America/Chicago", "ME": "America/New_York",
+    "MD": "America/New_York", "MA": "America/New_York", "MI": "America/Detroit", "MN": "America/Chicago",
+    "MS": "America/Chicago", "MO": "America/Chicago", "MT": "America/Denver", "NE": "America/Chicago",
+    "NV": "America/Los_Angeles", "NH": "America/New_York", "NJ": "America/New_York", "NM": "America/Denver",
+    "NY": "America/New_York", "NC": "America/New_York", "ND": "America/Chicago", "OH": "America/New_York",
+    "OK": "America/Chicago", "OR": "America/Los_Angeles", "PA": "America/New_York", "RI": "America/New_York",
+    "SC": "America/New_York", "SD": "America/Chicago", "TN": "America/Chicago", "TX": "America/Chicago",
+    "UT": "America/Denver", "VT": "America/New_York", "VA": "America/New_York", "WA": "America/Los_Angeles",
+    "WV": "America/New_York", "WI": "America/Chicago", "WY": "America/Denver",
+}
+
+# ISO 3166-1 alpha-2 country code -> IANA zone (capital / most populous zone)
+_COUNTRY_TIMEZONES = {
+    "US": "America/New_York", "CA": "America/Toronto", "MX": "America/Mexico_City", "BR": "America/Sao_Paulo",
+    "AR": "America/Argentina/Buenos_Aires", "GB": "Europe/London", "IE": "Europe/Dublin", "FR": "Europe/Paris",
+    "DE": "Europe/Berlin", "NL": "Europe/Amsterdam", "BE": "Europe/Brussels", "ES": "Europe/Madrid",
+    "PT": "Europe/Lisbon", "IT": "Europe/Rome", "CH": "Europe/Zurich", "AT": "Europe/Vienna",
+    "SE": "Europe/Stockholm", "NO": "Europe/Oslo", "DK": "Europe/Copenhagen", "FI": "Europe/Helsinki",
+    "PL": "Europe/Warsaw", "CZ": "Europe/Prague", "GR": "Europe/Athens", "UA": "Europe/Kyiv",
+    "TR": "Europe/Istanbul", "RU": "Europe/Moscow", "IL": "Asia/Jerusalem", "AE": "Asia/Dubai",
+    "IN": "Asia/Kolkata", "CN": "Asia/Shanghai", "HK": "Asia/Hong_Kong", "JP": "Asia/Tokyo",
+    "KR": "Asia/Seoul", "SG": "Asia/Singapore", "TH": "Asia/Bangkok", "ID": "Asia/Jakarta",
+    "PH": "Asia/Manila", "AU": "Australia/Sydney", "NZ": "Pacific/Auckland", "ZA": "Africa/Johannesburg",
+    "NG": "Africa/Lagos", "EG": "Africa/Cairo", "KE": "Africa/Nairobi",
+}
+
+_US_COUNTRY_NAMES = {"US", "USA", "UNITED STATES", "UNITED STATES OF AMERICA"}
+
+
+def generate_timezone_for_address(address: Address) -> str:
+    """
+    IANA timezone consistent with the address: the US state when the address is in the US
+    (or has no country), else the country code. Falls back to "UTC" when neither is known.
+    """
+    state = (address.state or "").strip().upper()
+    country = (address.country or "").strip().upper()
+
+    if state in _US_STATE_TIMEZONES and (not country or country in _US_COUNTRY_NAMES):
+        return _US_STATE_TIMEZONES[state]
+    if country in _US_COUNTRY_NAMES:
+        return _COUNTRY_TIMEZONES["US"]
+    return _COUNTRY_TIMEZONES.get(country, "UTC")
+
+
+def generate_full_pii(site_type: SiteType = SiteType.OTHER) -> PII:
+    """
+    Generates a PII record with a consistent name and an occupation suited to the site.
+    """
+    first = generate_first_name()
+    last = generate_last_name()
+    return PII(
+        first_name=first,
+        last_name=last,
+        full_name=f"{first} {last}",
+        occupation=generate_occupation(site_type),
+    )
+
+
+# First octets of /8 blocks delegated to each regional internet registry
+# (ARIN, RIPE NCC, APNIC). Only whole blocks that are globally routable.
+_IPV4_FIRST_OCTETS = {
+    IpRegion.US: [3, 4, 8, 12, 13, 15, 16, 18, 20, 23, 24, 34, 35, 40, 44, 45, 47, 50, 52, 54,
+                  63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 96, 97, 98, 99, 104,
+                  107, 108, 173, 174, 184, 199, 204, 205, 206, 207, 208, 209],
+    IpRegion.EU: [2, 5, 31, 37, 46, 62, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90,
+                  91, 92, 93, 94, 95, 109, 141, 145, 151, 176, 178, 185, 188, 193, 194, 195,
+                  212, 213, 217],
+    IpRegion.ASIA: [1, 14, 27, 36, 39, 42, 49, 58, 59, 60, 61, 101, 103, 110, 111, 112, 113,
+                    114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 175, 180,
+                    182, 183, 202, 203, 210, 211, 218, 219, 220, 221, 222, 223],
+}
+
+# First 16 bits of the /12 blocks each registry allocates from.
+_IPV6_PREFIXES = {
+    IpRegion.US: 0x2600,
+    IpRegion.EU: 0x2A00,
+    IpRegion.ASIA: 0x2400,
+}
+
+
+def generate_ipv4(region: IpRegion) -> ipaddress.IPv4Address:
+    """
+    Generates a plausible, globally routable IPv4 address for the given region.
+    Reserved ranges (loopback, private, link-local, RFC 5737 documentation) are never returned.
+    """
+    while True:
+        first = random.choice(_IPV4_FIRST_OCTETS[region])
+        addr = ipaddress.IPv4Address((first << 24) | random.getrandbits(24))
+        if addr.is_global and not addr.is_multicast:
+            return addr
+
+
+def generate_ipv6(region: IpRegion) -> ipaddress.IPv6Address:
+    """
+    Generates a plausible, globally routable IPv6 address for the given region.
+    """
+    while True:
+        # keep the top 12 bits of the registry prefix, randomize everything below
+        prefix = _IPV6_PREFIXES[region] | random.getrandbits(4)
+        addr = ipaddress.IPv6Address((prefix << 112) | random.getrandbits(112))
+        if addr.is_global:
+            return addr
+
+
+def generate_color_for_profile(profile_id: UUID) -> Tuple[int, int, int]:
+    """
+    Derives a stable RGB color from a profile ID (same ID -> same color).
+    The first three UUID bytes pick hue, saturation and lightness; saturation and
+    lightness are kept in a mid range so colors stay distinct and never too dark.
+    """
+    h_byte, s_byte, l_byte = profile_id.bytes[:3]
+    hue = h_byte / 255
+    saturation = 0.55 + (s_byte / 255) * 0.30  # 0.55 - 0.85
+    lightness = 0.45 + (l_byte / 255) * 0.20  # 0.45 - 0.65
+    r, g, b = colorsys.hls_to_rgb(hue, lightness, saturation)
+    return round(r * 255), round(g * 255), round(b * 255)
+
+
+def generate_initials_for_profile(profile: Profile) -> str:
+    """
+    Returns 1-2 uppercase characters for an avatar: the initials of the first two
+    words of the title, or the first two characters of a single-word title.
+    """
+    words = profile.title.split()
+    if not words:
+        return "?"
+    if len(words) >= 2:
+        return (words[0][0] + words[1][0]).upper()
+    return words[0][:2].upper()
@@ -1,22 +1,34 @@
-from enum import Enum
-from typing import Optional
-from datetime import datetime, date
-from uuid import UUID
-from pydantic import BaseModel, Field, ConfigDict
+import hashlib
+import io
+import json
+import random
+import re
+import tomllib
+import webbrowser
+from typing import Any, Dict, List, Optional, Tuple
+from datetime import datetime, date, timezone
+from urllib.parse import urlsplit
+from uuid import UUID, uuid4
+from pydantic import BaseModel, Field, ConfigDict, PrivateAttr, ValidationError, model_serializer, model_validator
 
-# ---------------------------------------------------------------------------
-# Mocks for types imported from crate::profile::types
-# In a real project, these would be imported from another module.
-# ---------------------------------------------------------------------------
-class SiteType(str, Enum):
-    # Example placeholder values
-    BLOG = "BLOG"
-    ECOMMERCE = "ECOMMERCE"
+from profile.types import SiteType, TrustLevel
+from profile_secrets.strength import PasswordStrength, password_strength
+from vault.errors import DeserializationError, InvalidArgument, NotSupported, VaultError
+
+# QR codes (optional, `qrcode`; PNG output also needs Pillow)
+try:
+    import qrcode
+    import qrcode.image.svg
+    HAVE_QRCODE = True
+except ImportError:
+    HAVE_QRCODE = False
 
-class TrustLevel(str, Enum):
-    # Example placeholder values
-    LOW = "LOW"
-    HIGH = "HIGH"
+TABLE_VALUE_MAX_LEN = 60
+ANONYMIZED_EMAIL = "user@example.invalid"
+ANONYMIZED_ALIAS_EMAIL = "alias@example.invalid"
+TABLE_MASKED_FIELDS = {"password_ref"}
+
+_HOSTNAME = re.compile(r"^(?=.{1,253}$)([a-z0-9]([a-z0-9-]{0,61}[a-z0-9])?\.)*[a-z0-9]([a-z0-9-]{0,61}[a-z0-9])?$", re.IGNORECASE)
 
 # ---------------------------------------------------------------------------
 # Data Models
@@ -33,11 +45,40 @@ class Audit(BaseModel):
     updated_at: datetime
     last_used_at: datetime
 
+    @model_validator(mode="before")
+    @classmethod
+    def _fill_missing_timestamps(cls, data: Any) -> Any:
+        # missing timestamps share one instant instead of each calling now()
+        if isinstance(data, dict):
+            now = datetime.now(timezone.utc)
+            data = {**{name: now for name in ("created_at", "updated_at", "last_used_at")}, **data}
+        return data
+
+    @classmethod
+    def new(cls) -> "Audit":
+        """All three timestamps set to the same current UTC instant."""
+        return cls()
+
 class Credentials(BaseModel):
     username: Optional[str] = None
     email: str
     password_ref: Optional[str] = None
 
+    def strength_report(self, store) -> Optional[PasswordStrength]:
+        """
+        Looks up the referenced secret in the vault and rates its password.
+        Returns None if there is no reference, the secret is missing, or it has no password.
+        """
+        if not self.password_ref:
+            return None
+        try:
+            secret = store.load_secret(self.password_ref)
+        except KeyError:
+            return None
+        if not secret.password:
+            return None
+        return password_strength(secret.password)
+
 class Address(BaseModel):
     apartment: Optional[str] = None
     street: Optional[str] = None
@@ -69,15 +110,311 @@ class PII(BaseModel):
     gender: Optional[str] = None
     race: Optional[str] = None
     nationality: Optional[str] = None
+    occupation: Optional[str] = None
+    timezone: Optional[str] = None  # IANA name, e.g. "America/New_York"
+    bio: Optional[str] = None
     legal_documents: Optional[LegalDocuments] = None
 
+    def age(self) -> Optional[int]:
+        """
+        Age in whole years (UTC today) from `dob` as YYYY-MM-DD; None if absent, unparseable, or in the future.
+        """
+        if not self.dob:
+            return None
+        try:
+            born = date.fromisoformat(self.dob)
+        except ValueError:
+            return None
+        today = datetime.now(timezone.utc).date()
+        years = today.year - born.year - ((today.month, today.day) < (born.month, born.day))
+        return years if years >= 0 else None
+
+    def age_appropriate_for_site(self, site_type: SiteType) -> bool:
+        """
+        True if old enough for the site (see SiteType.minimum_age), or if the age is unknown.
+        """
+        age = self.age()
+        return age is None or age >= site_type.minimum_age()
+
 class Profile(BaseModel):
     id: UUID
     domain: str
     title: str
-    credentials: Optional[Credentials] = None
     pii: Optional[PII] = None
+    notes: Optional[str] = None
+    tags: List[str] = Field(default_factory=list)
+    linked_alias_id: Optional[int] = None
+    linked_alias_email: Optional[str] = None
     audit: Audit
+    # private so every change goes through set_credentials / credentials_mut, which bump
+    # audit.updated_at; still read from and written to the "credentials" key
+    _credentials: Optional[Credentials] = PrivateAttr(default=None)
+
+    @model_validator(mode="wrap")
+    @classmethod
+    def _load_credentials(cls, data: Any, handler) -> "Profile":
+        credentials = None
+        if isinstance(data, dict) and "credentials" in data:
+            data = dict(data)
+            credentials = data.pop("credentials")
+        profile = handler(data)
+        if credentials is not None:
+            profile._credentials = Credentials.model_validate(credentials)
+        return profile
+
+    @model_serializer(mode="wrap")
+    def _dump_credentials(self, handler, info) -> Dict[str, Any]:
+        data = handler(self)
+        if self._credentials is None and info.exclude_none:
+            return data
+        credentials = None
+        if self._credentials is not None:
+            credentials = self._credentials.model_dump(mode=info.mode, exclude_none=info.exclude_none)
+        # keep the key where the field used to be, right after the title
+        out = {}
+        for key, value in data.items():
+            out[key] = value
+            if key == "title":
+                out["credentials"] = credentials
+        return out
+
+    @property
+    def credentials(self) -> Optional[Credentials]:
+        """Read-only view; use credentials_mut() or set_credentials() to change them."""
+        return self._credentials
+
+    def credentials_mut(self) -> Optional[Credentials]:
+        """
+        The credentials for in-place edits, or None if there are none. Bumps audit.updated_at,
+        since the caller is about to change them.
+        """
+        if self._credentials is not None:
+            self.audit.updated_at = datetime.now(timezone.utc)
+        return self._credentials
+
+    def set_credentials(self, credentials: Optional[Credentials]):
+        """
+        Replaces the credentials and bumps audit.updated_at.
+        """
+        self._credentials = credentials
+        self.audit.updated_at = datetime.now(timezone.utc)
+
+    def link_alias(self, alias_id: int, email: Optional[str] = None):
+        """
+        Associates a SimpleLogin alias with the profile and bumps audit.updated_at.
+        """
+        self.linked_alias_id = alias_id
+        self.linked_alias_email = email
+        self.audit.updated_at = datetime.now(timezone.utc)
+
+    def clone_anonymized(self) -> "Profile":
+        """
+        Copy with a new id that keeps the structure (domain, title, tags, linked alias id)
+        but no personal data: every PII field is None, the credentials and alias emails are
+        replaced with placeholders (ANONYMIZED_EMAIL / ANONYMIZED_ALIAS_EMAIL), the username,
+        password reference and free-form notes are dropped and the audit timestamps restarted.
+        Meant for sharing a vault layout, e.g. with support.
+        """
+        clone = self.model_copy(deep=True, update={
+            "id": uuid4(),
+            "pii": PII() if self.pii is not None else None,
+            "notes": None,
+            "linked_alias_email": ANONYMIZED_ALIAS_EMAIL if self.linked_alias_email else None,
+            "audit": Audit.new(),
+        })
+        if self._credentials is not None:
+            clone._credentials = Credentials(email=ANONYMIZED_EMAIL)
+        return clone
+
+    def recommended_password_length(self) -> int:
+        """
+        Minimum password length for this profile, based on the site type guessed from its domain.
+        """
+        return SiteType.from_domain_hint(self.domain).recommended_password_length()
+
+    def age(self) -> Optional[int]:
+        return self.pii.age() if self.pii else None
+
+    def is_adult(self) -> Optional[bool]:
+        """
+        True if 18 or older; None when the age is unknown.
+        """
+        age = self.age()
+        return None if age is None else age >= 18
+
+    def site_url(self) -> Optional[str]:
+        """
+        HTTPS URL for the profile's domain ("https://" is added when no scheme is given).
+        None if the domain doesn't form a valid http(s) URL.
+        """
+        domain = self.domain.strip()
+        url = domain if "://" in domain else f"https://{domain}"
+        try:
+            parts = urlsplit(url)
+            hostname = parts.hostname
+            _ = parts.port  # raises ValueError on a malformed port
+        except ValueError:
+            return None
+        if parts.scheme not in ("http", "https") or not hostname or not _HOSTNAME.match(hostname):
+            return None
+        return url
+
+    def open_in_browser(self):
+        """
+        Opens site_url() in the default web browser.
+        """
+        url = self.site_url()
+        if url is None:
+            raise InvalidArgument(f"Profile domain is not a valid URL: {self.domain!r}")
+        if not webbrowser.open(url):
+            raise VaultError("Could not open a web browser")
+
+    def consistent_seed_rng(self, field: str) -> random.Random:
+        """
+        RNG seeded from SHA-256(profile id || field), so generators re-run for the same
+        profile and field produce the same values (e.g. to recreate a lost profile).
+        Deterministic by design: don't use it for passwords or other secrets.
+        """
+        digest = hashlib.sha256(self.id.bytes + field.encode("utf-8")).digest()
+        return random.Random(int.from_bytes(digest[:32], "big"))
+
+    def to_toml(self) -> str:
+        """
+        Human-editable TOML rendering. Dates and timestamps are written as ISO 8601 strings
+        (rather than TOML datetimes) so they round-trip through the same validators as JSON.
+        """
+        return _toml_dumps(self.model_dump(mode="json", exclude_none=True))
+
+    @classmethod
+    def from_toml(cls, s: str) -> "Profile":
+        try:
+            return cls(**tomllib.loads(s))
+        except (tomllib.TOMLDecodeError, ValidationError, TypeError) as e:
+            raise DeserializationError(f"Profile: {e}", s) from e
+
+    def render_table(self) -> str:
+        """
+        Multi-line ASCII table of every set field (nested fields as dotted paths), for terminal display.
+        Secret references are masked and long values truncated.
+        """
+        rows = [("Field", "Value")]
+        for path, value in _flatten(self.model_dump(mode="json", exclude_none=True)):
+            if path.rpartition(".")[2] in TABLE_MASKED_FIELDS:
+                text = "[hidden]"
+            else:
+                text = ", ".join(map(str, value)) if isinstance(value, list) else str(value)
+                text = _one_line(text)
+                if len(text) > TABLE_VALUE_MAX_LEN:
+                    text = text[:TABLE_VALUE_MAX_LEN - 1] + "…"
+            rows.append((path, text))
+
+        key_width = max(len(k) for k, _ in rows)
+        value_width = max(len(v) for _, v in rows)
+        rule = f"+-{'-' * key_width}-+-{'-' * value_width}-+"
+        lines = [rule]
+        for i, (key, text) in enumerate(rows):
+            lines.append(f"| {key.ljust(key_width)} | {text.ljust(value_width)} |")
+            if i == 0:
+                lines.append(rule)
+        lines.append(rule)
+        return "\n".join(lines)
+
+    def _qr_payload(self) -> str:
+        if not HAVE_QRCODE:
+            raise NotSupported("QR code output requires the `qrcode` package")
+        if self.credentials is None or not self.linked_alias_email:
+            raise InvalidArgument("QR code needs credentials and a linked alias email")
+        return f"{self.credentials.email}:{self.linked_alias_email}"
+
+    def to_qr_code_svg(self) -> str:
+        """
+        SVG QR code encoding "<credentials email>:<linked alias email>", for scanning
+        the login onto a phone. For display only: the password is never encoded,
+        the receiving device must fetch it from the vault separately.
+        """
+        payload = self._qr_payload()
+        img = qrcode.make(payload, image_factory=qrcode.image.svg.SvgPathImage)
+        return img.to_string(encoding="unicode")
+
+    def to_qr_code_png_bytes(self) -> bytes:
+        """
+        PNG version of to_qr_code_svg(); same payload, same caveat about the password.
+        """
+        payload = self._qr_payload()
+        try:
+            img = qrcode.make(payload)
+        except ImportError as e:
+            raise NotSupported("PNG QR codes require Pillow") from e
+        buf = io.BytesIO()
+        img.save(buf)
+        return buf.getvalue()
+
+    def to_form_fill_map(self) -> Dict[str, str]:
+        """
+        Maps profile fields to common HTML form field `name` attributes for autofill.
+        Fields that are not set are omitted.
+        """
+        creds = self.credentials
+        pii = self.pii
+        address = pii.address if pii else None
+
+        candidates = {
+            "email": creds.email if creds else None,
+            "username": creds.username if creds else None,
+            "name": pii.full_name if pii else None,
+            "first-name": pii.first_name if pii else None,
+            "middle-name": pii.middle_name if pii else None,
+            "last-name": pii.last_name if pii else None,
+            "bday": pii.dob if pii else None,
+            "phone": pii.phone_number if pii else None,
+            "sex": pii.gender if pii else None,
+            "organization-title": pii.occupation if pii else None,
+            "address1": address.street if address else None,
+            "address2": address.apartment if address else None,
+            "city": address.city if address else None,
+            "state": address.state if address else None,
+            "zip": address.postal_code if address else None,
+            "country": address.country if address else None,
+        }
+        return {k: v for k, v in candidates.items() if v}
+
+def _flatten(data: Dict[str, Any], prefix: str = "") -> List[Tuple[str, Any]]:
+    items = []
+    for key, value in data.items():
+        path = f"{prefix}.{key}" if prefix else key
+        if isinstance(value, dict):
+            items.extend(_flatten(value, path))
+        else:
+            items.append((path, value))
+    return items
+
+
+def _one_line(text: str) -> str:
+    return " ".join(text.split())
+
+
+def _toml_value(value: Any) -> str:
+    if isinstance(value, bool):
+        return "true" if value else "false"
+    if isinstance(value, (int, float)):
+        return repr(value)
+    if isinstance(value, list):
+        return "[" + ", ".join(_toml_value(v) for v in value) + "]"
+    # JSON string escapes are valid in TOML basic strings
+    return json.dumps(str(value), ensure_ascii=False)
+
+
+def _toml_dumps(data: Dict[str, Any], prefix: str = "") -> str:
+    """
+    Minimal TOML writer for model dumps: scalars and lists of scalars first, then nested tables.
+    """
+    lines = [f"{key} = {_toml_value(value)}" for key, value in data.items() if not isinstance(value, dict)]
+    for key, value in data.items():
+        if isinstance(value, dict):
+            name = f"{prefix}.{key}" if prefix else key
+            lines.append(f"\n[{name}]")
+            lines.append(_toml_dumps(value, name).rstrip("\n"))
+    return "\n".join(line for line in lines if line) + "\n"
 
 class ProfileIndex(BaseModel):
     id: UUID
@@ -92,11 +429,7 @@ class ProfileIndex(BaseModel):
 # ---------------------------------------------------------------------------
 if __name__ == "__main__":
     # Create an example Audit
-    audit_log = Audit(
-        created_at=datetime.utcnow(),
-        updated_at=datetime.utcnow(),
-        last_used_at=datetime.utcnow()
-    )
+    audit_log = Audit.new()
 
     # Create an example Profile
     # Note: We omit optional fields to show how they behave like Option::None
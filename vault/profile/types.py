@@ -1,4 +1,5 @@
 from enum import Enum
+from urllib.parse import urlsplit
 
 
 class SiteType(str, Enum):
@@ -29,6 +30,151 @@ class SiteType(str, Enum):
     CRYPTOCURRENCY = "cryptocurrency"
     OTHER = "other"  # fallback
 
+    @classmethod
+    def from_domain_hint(cls, domain: str) -> "SiteType":
+        """
+        Best-effort guess of the site type from the hostname (a bare domain or a URL).
+        Hints match whole labels ("bank" matches my-bank.com and bank.example, not
+        embankment.com) or whole domains ("x.com" matches x.com and api.x.com, not
+        netflix.com). Falls back to OTHER when nothing matches.
+        """
+        host = (domain or "").strip().lower()
+        if "://" in host:
+            host = urlsplit(host).hostname or ""
+        host = host.split("/")[0].split(":")[0].rstrip(".")
+        labels = host.split(".")
+        words = set(labels) | {w for label in labels for w in label.split("-")}
+        for hints, site_type in _DOMAIN_HINTS:
+            for hint in hints:
+                if "." in hint:
+                    if host == hint or host.endswith("." + hint):
+                        return site_type
+                elif hint in words:
+                    return site_type
+        return cls.OTHER
+
+    def recommended_password_length(self) -> int:
+        """
+        Recommended minimum password length for this kind of site.
+        High-value targets get longer passwords.
+        """
+        if self in _HIGH_VALUE_SITES:
+            return 24
+        if self in _LOW_VALUE_SITES:
+            return 16
+        return 20
+
+    def minimum_age(self) -> int:
+        """
+        Minimum account-holder age: 18 where gambling or financial risk is involved, 13 otherwise.
+        """
+        return 18 if self in _ADULT_ONLY_SITES else 13
+
+    def display_name(self) -> str:
+        """Human-readable label for UIs, e.g. "Developer Tools"."""
+        return _DISPLAY_NAMES[self]
+
+    def icon_emoji(self) -> str:
+        """Representative emoji for terminal UIs."""
+        return _ICON_EMOJIS[self]
+
+
+# Hostname hints -> SiteType, checked in order (first match wins). Hints containing a dot are
+# domains (matching themselves and their subdomains); the rest are whole labels or
+# hyphen-separated parts of a label.
+_DOMAIN_HINTS = [
+    (("gov",), SiteType.GOVERNMENT),
+    (("bank", "credit-union", "creditunion", "chase.com", "wellsfargo.com", "bankofamerica.com"), SiteType.BANK),
+    (("coin", "crypto", "wallet", "exchange", "coinbase.com", "binance.com", "kraken.com"), SiteType.CRYPTOCURRENCY),
+    (("insurance", "insure"), SiteType.INSURANCE),
+    (("health", "clinic", "hospital", "pharmacy", "medical"), SiteType.HEALTHCARE),
+    (("edu", "university", "college"), SiteType.UNIVERSITY),
+    (("airline", "airlines", "airways", "air"), SiteType.AIRLINE),
+    (("travel", "booking", "hotel", "hotels", "trip", "expedia", "tripadvisor"), SiteType.TRAVEL),
+    (("mail", "gmail", "protonmail", "proton", "outlook"), SiteType.EMAIL_PROVIDER),
+    (("drive", "dropbox", "cloud", "icloud", "box.com"), SiteType.CLOUD_STORAGE),
+    (("github", "gitlab", "bitbucket", "npmjs", "docker"), SiteType.DEVELOPER_TOOLS),
+    (("linkedin", "indeed", "glassdoor"), SiteType.PROFESSIONAL),
+    (("facebook", "twitter", "instagram", "tiktok", "reddit", "x.com"), SiteType.SOCIAL_MEDIA),
+    (("steam", "steampowered", "epicgames", "xbox", "playstation", "game", "games"), SiteType.GAMING),
+    (("netflix", "spotify", "hulu", "twitch", "youtube", "soundcloud"), SiteType.ENTERTAINMENT),
+    (("forum", "forums", "community", "stackexchange", "stackoverflow"), SiteType.FORUM),
+    (("news", "times", "nytimes"), SiteType.NEWS),
+    (("mobile", "telecom", "wireless", "verizon", "vodafone"), SiteType.TELECOM),
+    (("energy", "electric", "water", "gas"), SiteType.UTILITIES),
+    (("shop", "store", "amazon", "ebay", "etsy", "cart"), SiteType.ECOMMERCE),
+]
+
+_DISPLAY_NAMES = {
+    SiteType.BANK: "Bank",
+    SiteType.GOVERNMENT: "Government",
+    SiteType.UNIVERSITY: "University",
+    SiteType.HEALTHCARE: "Healthcare",
+    SiteType.INSURANCE: "Insurance",
+    SiteType.AIRLINE: "Airline",
+    SiteType.PROFESSIONAL: "Professional",
+    SiteType.TRAVEL: "Travel",
+    SiteType.ECOMMERCE: "E-commerce",
+    SiteType.SOCIAL_MEDIA: "Social Media",
+    SiteType.ENTERTAINMENT: "Entertainment",
+    SiteType.GAMING: "Gaming",
+    SiteType.UTILITIES: "Utilities",
+    SiteType.TELECOM: "Telecom",
+    SiteType.CLOUD_STORAGE: "Cloud Storage",
+    SiteType.EMAIL_PROVIDER: "Email Provider",
+    SiteType.NEWS: "News",
+    SiteType.FORUM: "Forum",
+    SiteType.DEVELOPER_TOOLS: "Developer Tools",
+    SiteType.CRYPTOCURRENCY: "Cryptocurrency",
+    SiteType.OTHER: "Other",
+}
+
+_ICON_EMOJIS = {
+    SiteType.BANK: "🏦",
+    SiteType.GOVERNMENT: "🏛️",
+    SiteType.UNIVERSITY: "🎓",
+    SiteType.HEALTHCARE: "🏥",
+    SiteType.INSURANCE: "🛡️",
+    SiteType.AIRLINE: "✈️",
+    SiteType.PROFESSIONAL: "💼",
+    SiteType.TRAVEL: "🧳",
+    SiteType.ECOMMERCE: "🛒",
+    SiteType.SOCIAL_MEDIA: "💬",
+    SiteType.ENTERTAINMENT: "🎬",
+    SiteType.GAMING: "🎮",
+    SiteType.UTILITIES: "💡",
+    SiteType.TELECOM: "📱",
+    SiteType.CLOUD_STORAGE: "☁️",
+    SiteType.EMAIL_PROVIDER: "📧",
+    SiteType.NEWS: "📰",
+    SiteType.FORUM: "🗣️",
+    SiteType.DEVELOPER_TOOLS: "🛠️",
+    SiteType.CRYPTOCURRENCY: "🪙",
+    SiteType.OTHER: "🌐",
+}
+
+_HIGH_VALUE_SITES = {
+    SiteType.BANK,
+    SiteType.GOVERNMENT,
+    SiteType.CRYPTOCURRENCY,
+    SiteType.HEALTHCARE,
+    SiteType.INSURANCE,
+    SiteType.EMAIL_PROVIDER,
+    SiteType.CLOUD_STORAGE,
+}
+
+_ADULT_ONLY_SITES = {
+    SiteType.CRYPTOCURRENCY,
+    SiteType.GAMING,  # includes gambling / betting sites
+}
+
+_LOW_VALUE_SITES = {
+    SiteType.GAMING,
+    SiteType.FORUM,
+    SiteType.ENTERTAINMENT,
+    SiteType.NEWS,
+}
+
 
 class TrustLevel(str, Enum):
     """
@@ -37,3 +183,12 @@ class TrustLevel(str, Enum):
     REAL = "real"  # e.g., banks, government
     PROMPT = "prompt"  # e.g., social media, ecommerce
     ALIAS = "alias"  # e.g., forums, entertainment
+
+
+class IpRegion(str, Enum):
+    """
+    Coarse geographic region used when generating IP addresses.
+    """
+    US = "us"
+    EU = "eu"
+    ASIA = "asia"
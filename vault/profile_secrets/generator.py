@@ -1,6 +1,32 @@
 import random
 import secrets
 import string
+from typing import List, Sequence, Tuple
+
+from utils.random import pick
+
+# We use string constants for letters/digits, but define symbols
+# explicitly to match the specific Rust byte string exactly.
+UPPERCASE = string.ascii_uppercase
+LOWERCASE = string.ascii_lowercase
+DIGITS = string.digits
+SYMBOLS = "!@#$%^&*()-_=+[]{}<>?/"
+
+# Easy-to-picture words for memorable PINs, the same number for every length 2..11 so each
+# digit (length mod 10) is equally likely. Too few for passphrases; only the digits are secret.
+_PIN_WORDS = (
+    "ox", "up", "go", "me", "hi", "on",
+    "cat", "sun", "owl", "fig", "map", "jet",
+    "lamp", "frog", "rose", "kite", "moon", "boat",
+    "apple", "tiger", "cloud", "river", "piano", "bread",
+    "garden", "rocket", "castle", "pepper", "button", "monkey",
+    "blanket", "dolphin", "lantern", "pumpkin", "teacher", "volcano",
+    "elephant", "mountain", "umbrella", "sandwich", "dinosaur", "hospital",
+    "butterfly", "chocolate", "telescope", "pineapple", "adventure", "crocodile",
+    "strawberry", "basketball", "lighthouse", "helicopter", "skateboard", "watermelon",
+    "grasshopper", "photography", "playgrounds", "firefighter", "marshmallow", "caterpillar",
+)
+_PIN_WORDS_BY_DIGIT = {d: [w for w in _PIN_WORDS if len(w) % 10 == d] for d in range(10)}
 
 
 def generate_secure_password(length: int) -> str:
@@ -11,24 +37,16 @@ def generate_secure_password(length: int) -> str:
     if length < 8:
         raise ValueError("Password should be at least 8 characters")
 
-    # Define constants
-    # We use string constants for letters/digits, but define symbols
-    # explicitly to match the specific Rust byte string exactly.
-    uppercase = string.ascii_uppercase
-    lowercase = string.ascii_lowercase
-    digits = string.digits
-    symbols = "!@#$%^&*()-_=+[]{}<>?/"
-
     password_chars = []
 
     # Ensure at least one of each category using a cryptographically secure RNG
-    password_chars.append(secrets.choice(uppercase))
-    password_chars.append(secrets.choice(lowercase))
-    password_chars.append(secrets.choice(digits))
-    password_chars.append(secrets.choice(symbols))
+    password_chars.append(secrets.choice(UPPERCASE))
+    password_chars.append(secrets.choice(LOWERCASE))
+    password_chars.append(secrets.choice(DIGITS))
+    password_chars.append(secrets.choice(SYMBOLS))
 
     # Fill the rest from all categories
-    all_chars = uppercase + lowercase + digits + symbols
+    all_chars = UPPERCASE + LOWERCASE + DIGITS + SYMBOLS
     remaining_length = length - 4
 
     for _ in range(remaining_length):
@@ -39,3 +57,38 @@ def generate_secure_password(length: int) -> str:
     random.SystemRandom().shuffle(password_chars)
 
     return "".join(password_chars)
+
+
+def generate_pin(digits: int) -> str:
+    """
+    Generates a numeric PIN of `digits` digits (at least 4).
+    """
+    if digits < 4:
+        raise ValueError("PIN should be at least 4 digits")
+    return "".join(pick(DIGITS) for _ in range(digits))
+
+
+def generate_hex_token(num_bytes: int) -> str:
+    """
+    Generates `num_bytes` random bytes as lowercase hex (for API tokens, webhook secrets).
+    """
+    return secrets.token_hex(num_bytes)
+
+
+def generate_memorable_pin(words: int) -> Tuple[str, List[str]]:
+    """
+    Generates a PIN of `words` digits (at least 4) together with a word per digit whose
+    length mod 10 is that digit ("tiger cat ox strawberry" -> "5320"), so the PIN can be
+    remembered as a short story. Digits are uniform, so the PIN is as strong as generate_pin().
+    """
+    if words < 4:
+        raise ValueError("PIN should be at least 4 digits")
+    chosen = [pick(_PIN_WORDS_BY_DIGIT[int(pick(DIGITS))]) for _ in range(words)]
+    return decode_pin_mnemonic(chosen), chosen
+
+
+def decode_pin_mnemonic(words: Sequence[str]) -> str:
+    """
+    Rebuilds the PIN from its mnemonic words: each word's length mod 10.
+    """
+    return "".join(str(len(w) % 10) for w in words)
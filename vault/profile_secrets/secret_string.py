@@ -0,0 +1,34 @@
+class SecretString:
+    """
+    Holds a secret in a bytearray that is overwritten with zeros on clear() or when the
+    object is garbage collected. repr()/str() never show the value; use reveal().
+    Best effort: str copies returned by reveal() (or made before wrapping) can't be wiped.
+    """
+    __slots__ = ("_buf",)
+
+    def __init__(self, value: str):
+        self._buf = bytearray(value.encode("utf-8"))
+
+    def reveal(self) -> str:
+        return self._buf.decode("utf-8")
+
+    def clear(self):
+        self._buf[:] = bytes(len(self._buf))
+        self._buf = bytearray()
+
+    def __len__(self) -> int:
+        return len(self._buf)
+
+    def __repr__(self) -> str:
+        return "SecretString('***')"
+
+    __str__ = __repr__
+
+    def __enter__(self) -> "SecretString":
+        return self
+
+    def __exit__(self, exc_type, exc_val, exc_tb):
+        self.clear()
+
+    def __del__(self):
+        self.clear()
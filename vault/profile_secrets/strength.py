@@ -0,0 +1,72 @@
+import math
+import string
+from enum import IntEnum
+
+
+class PasswordStrength(IntEnum):
+    """
+    Coarse strength buckets; ordered so that comparisons like `< STRONG` work.
+    """
+    WEAK = 0
+    FAIR = 1
+    STRONG = 2
+    VERY_STRONG = 3
+
+
+def estimate_entropy_bits(password: str) -> float:
+    """
+    Estimates entropy as length * log2(pool size), where the pool is the union
+    of the character classes actually used. This overestimates dictionary words
+    but is good enough to flag short or single-class passwords.
+    """
+    if not password:
+        return 0.0
+
+    pool = 0
+    if any(c in string.ascii_lowercase for c in password):
+        pool += len(string.ascii_lowercase)
+    if any(c in string.ascii_uppercase for c in password):
+        pool += len(string.ascii_uppercase)
+    if any(c in string.digits for c in password):
+        pool += len(string.digits)
+    if any(not c.isalnum() for c in password):
+        pool += 32  # printable ASCII symbols
+    if any(c.isalnum() and not c.isascii() for c in password):
+        pool += 64  # other scripts (accented, Cyrillic, CJK, ...); deliberately conservative
+
+    # Repeated characters add little; only count distinct ones beyond the first few.
+    effective_length = min(len(password), len(set(password)) * 2)
+    return effective_length * math.log2(pool)
+
+
+def password_strength(password: str) -> PasswordStrength:
+    """
+    Classifies a password into a PasswordStrength bucket.
+    """
+    if len(password) < 8:
+        return PasswordStrength.WEAK
+
+    bits = estimate_entropy_bits(password)
+    if bits < 40:
+        return PasswordStrength.WEAK
+    if bits < 60:
+        return PasswordStrength.FAIR
+    if bits < 80:
+        return PasswordStrength.STRONG
+    return PasswordStrength.VERY_STRONG
+
+
+def normalized_levenshtein(a: str, b: str) -> float:
+    """
+    1.0 for identical strings down to 0.0 for completely different ones:
+    1 - edit_distance / max(len(a), len(b)).
+    """
+    if not a and not b:
+        return 1.0
+    previous = list(range(len(b) + 1))
+    for i, ca in enumerate(a, start=1):
+        current = [i]
+        for j, cb in enumerate(b, start=1):
+            current.append(min(previous[j] + 1, current[j - 1] + 1, previous[j - 1] + (ca != cb)))
+        previous = current
+    return 1.0 - previous[-1] / max(len(a), len(b))
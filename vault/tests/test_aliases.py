@@ -0,0 +1,33 @@
+import unittest
+
+from email_aliases.aliases import AliasClient
+from vault.errors import InvalidArgument
+
+
+class _NoNetworkClient:
+    """HttpClient that fails the test if any request is attempted."""
+
+    def __init__(self):
+        self.calls = []
+
+    async def _request(self, url, **kwargs):
+        self.calls.append(url)
+        raise AssertionError(f"unexpected request to {url}")
+
+    get = post = patch = delete = _request
+
+    async def aclose(self):
+        pass
+
+
+class CreateRandomAliasTest(unittest.IsolatedAsyncioTestCase):
+    async def test_unknown_mode_is_rejected_locally(self):
+        http = _NoNetworkClient()
+        client = AliasClient(base_url="https://sl.example", api_key="key", client=http)
+        with self.assertRaises(InvalidArgument):
+            await client.create_random_alias(mode="emoji")
+        self.assertEqual(http.calls, [])
+
+
+if __name__ == "__main__":
+    unittest.main()
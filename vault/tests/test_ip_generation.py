@@ -0,0 +1,23 @@
+import unittest
+
+from profile.generator import IpRegion, generate_ipv4, generate_ipv6
+
+
+class IpGenerationTest(unittest.TestCase):
+    def test_ipv4_is_globally_routable(self):
+        for region in IpRegion:
+            for _ in range(500):
+                addr = generate_ipv4(region)
+                self.assertTrue(addr.is_global, addr)
+                self.assertFalse(addr.is_private or addr.is_loopback or addr.is_link_local or addr.is_multicast, addr)
+
+    def test_ipv6_is_globally_routable(self):
+        for region in IpRegion:
+            for _ in range(500):
+                addr = generate_ipv6(region)
+                self.assertTrue(addr.is_global, addr)
+                self.assertFalse(addr.is_private or addr.is_loopback or addr.is_link_local or addr.is_multicast, addr)
+
+
+if __name__ == "__main__":
+    unittest.main()
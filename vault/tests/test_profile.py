@@ -0,0 +1,47 @@
+import json
+import unittest
+from datetime import datetime, timedelta, timezone
+
+from profile.models import Audit, Credentials, Profile
+
+
+def _profile() -> Profile:
+    return Profile(
+        id="550e8400-e29b-41d4-a716-446655440000",
+        domain="example.com",
+        title="Example",
+        credentials={"email": "me@example.com", "password_ref": "sec-1"},
+        audit=Audit.new(),
+    )
+
+
+class CredentialsTest(unittest.TestCase):
+    def test_serialized_under_credentials_key(self):
+        profile = _profile()
+        data = json.loads(profile.model_dump_json(exclude_none=True))
+        self.assertEqual(data["credentials"], {"email": "me@example.com", "password_ref": "sec-1"})
+        self.assertEqual(Profile.model_validate(data), profile)
+        self.assertEqual(Profile.from_toml(profile.to_toml()), profile)
+
+    def test_cannot_be_assigned_directly(self):
+        profile = _profile()
+        with self.assertRaises(AttributeError):
+            profile.credentials = Credentials(email="other@example.com")
+
+    def test_changes_bump_updated_at(self):
+        profile = _profile()
+        stale = datetime.now(timezone.utc) - timedelta(days=1)
+
+        profile.audit.updated_at = stale
+        profile.credentials_mut().username = "me"
+        self.assertGreater(profile.audit.updated_at, stale)
+        self.assertEqual(profile.credentials.username, "me")
+
+        profile.audit.updated_at = stale
+        profile.set_credentials(None)
+        self.assertGreater(profile.audit.updated_at, stale)
+        self.assertIsNone(profile.credentials_mut())
+
+
+if __name__ == "__main__":
+    unittest.main()
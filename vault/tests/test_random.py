@@ -0,0 +1,17 @@
+import unittest
+
+from utils.random import secure_token
+
+
+class SecureTokenTest(unittest.TestCase):
+    def test_tokens_differ(self):
+        self.assertNotEqual(secure_token(32), secure_token(32))
+
+    def test_token_is_urlsafe_without_padding(self):
+        token = secure_token(32)
+        self.assertEqual(len(token), 43)  # 32 bytes of base64 without "="
+        self.assertTrue(all(c.isalnum() or c in "-_" for c in token))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,38 @@
+import unittest
+
+from profile.types import SiteType
+
+
+class FromDomainHintTest(unittest.TestCase):
+    def test_keywords_do_not_match_inside_other_words(self):
+        cases = {
+            "netflix.com": SiteType.ENTERTAINMENT,
+            "firefox.com": SiteType.OTHER,
+            "stackexchange.com": SiteType.FORUM,
+            "hair.com": SiteType.OTHER,
+            "vegas.com": SiteType.OTHER,
+            "soundcloud.com": SiteType.ENTERTAINMENT,
+        }
+        for domain, expected in cases.items():
+            self.assertEqual(SiteType.from_domain_hint(domain), expected, domain)
+
+    def test_labels_and_domains_match(self):
+        cases = {
+            "x.com": SiteType.SOCIAL_MEDIA,
+            "api.x.com": SiteType.SOCIAL_MEDIA,
+            "www.usa.gov": SiteType.GOVERNMENT,
+            "service.gov.uk": SiteType.GOVERNMENT,
+            "my-bank.example": SiteType.BANK,
+            "https://github.com/login": SiteType.DEVELOPER_TOOLS,
+            "mail.google.com": SiteType.EMAIL_PROVIDER,
+            "example.org": SiteType.OTHER,
+        }
+        for domain, expected in cases.items():
+            self.assertEqual(SiteType.from_domain_hint(domain), expected, domain)
+
+    def test_stackexchange_keeps_default_policy(self):
+        self.assertEqual(SiteType.from_domain_hint("stackexchange.com").minimum_age(), 13)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,17 @@
+import unittest
+
+from profile_secrets.strength import PasswordStrength, estimate_entropy_bits, password_strength
+
+
+class EntropyTest(unittest.TestCase):
+    def test_non_ascii_password_has_entropy(self):
+        for password in ("éééééééé", "пароль123", "密码密码密码密码"):
+            self.assertGreater(estimate_entropy_bits(password), 0.0, password)
+
+    def test_non_ascii_password_is_rated(self):
+        self.assertEqual(password_strength("éééééééé"), PasswordStrength.WEAK)
+        self.assertGreaterEqual(password_strength("Çalışkan-Ğüneş-Ölçü-2024"), PasswordStrength.STRONG)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,84 @@
+import os
+import secrets
+import tempfile
+import unittest
+
+from vault.audit import AuditLog, ItemArchived, ItemPurged, MasterKeyRotated
+from vault.errors import Unauthorized
+from vault.vault import Vault
+
+
+class _VaultTestCase(unittest.TestCase):
+    """Temporary vault holding one identity ("item-1") with a secret and a file."""
+
+    def setUp(self):
+        # the blob directory is relative to the working directory
+        self._cwd = os.getcwd()
+        self._tmp = tempfile.TemporaryDirectory()
+        os.chdir(self._tmp.name)
+        self.db_path = os.path.join(self._tmp.name, "index.sqlite")
+        self.vault = self._open()
+        self.vault.unlock("old password", use_argon2=False)
+        self.vault.create_identity("item-1", "example.com", "Example", {"email": "me@example.com"})
+        self.vault.create_secret("sec-1", "item-1", "password", "me", "hunter2-hunter2")
+        self.vault.add_file("file-1", "item-1", "note.txt", "text/plain", b"attachment")
+
+    def tearDown(self):
+        os.chdir(self._cwd)
+        self._tmp.cleanup()
+
+    def _open(self) -> Vault:
+        return Vault(db_path=self.db_path, audit_log=AuditLog(os.path.join(self._tmp.name, "audit.jsonl")))
+
+    def _assert_readable(self, vault: Vault):
+        self.assertEqual(vault.load_identity("item-1").email, "me@example.com")
+        self.assertEqual(vault.load_secret("sec-1").password, "hunter2-hunter2")
+        self.assertEqual(vault.load_file("file-1"), b"attachment")
+
+
+class RotateKeyTest(_VaultTestCase):
+    def test_readable_after_rotate_key(self):
+        old_key = bytes(self.vault.mk)
+        new_key = secrets.token_bytes(32)
+        self.assertEqual(self.vault.rotate_key(old_key, new_key), 3)
+        self._assert_readable(self.vault)
+
+    def test_readable_after_change_password_and_reopen(self):
+        self.vault.change_password("old password", "new password", use_argon2=False)
+        self._assert_readable(self.vault)
+
+        reopened = self._open()
+        reopened.unlock("new password", use_argon2=False)
+        self._assert_readable(reopened)
+
+    def test_wrong_current_password_is_rejected(self):
+        with self.assertRaises(Unauthorized):
+            self.vault.change_password("wrong password", "new password", use_argon2=False)
+        self._assert_readable(self.vault)
+
+    def test_wrong_old_key_is_rejected_while_locked(self):
+        self.vault.lock()
+        with self.assertRaises(Unauthorized):
+            self.vault.rotate_key(secrets.token_bytes(32), secrets.token_bytes(32))
+        self.vault.unlock("old password", use_argon2=False)
+        self._assert_readable(self.vault)
+
+    def test_rotation_is_audited(self):
+        self.vault.rotate_key(bytes(self.vault.mk), secrets.token_bytes(32))
+        events = [event for _ts, event in self.vault.audit.replay()]
+        self.assertIn(MasterKeyRotated(entries=3), events)
+
+
+class VacuumAuditTest(_VaultTestCase):
+    def test_archive_and_purge_are_audited(self):
+        self.vault.archive_item("item-1")
+        self.vault.vacuum(retention_days=-1)  # everything archived counts as expired
+
+        events = [event for _ts, event in self.vault.audit.replay()]
+        self.assertIn(ItemArchived(item_id="item-1"), events)
+        self.assertIn(ItemPurged(item_id="item-1", secret_ids=["sec-1"], file_ids=["file-1"]), events)
+        self.assertEqual(self.vault.list_items(), [])
+
+
+if __name__ == "__main__":
+    unittest.main()
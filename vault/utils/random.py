@@ -0,0 +1,39 @@
+import secrets
+from datetime import timedelta
+from typing import Sequence, TypeVar
+
+T = TypeVar("T")
+
+_sysrand = secrets.SystemRandom()
+
+
+def pick(items: Sequence[T]) -> T:
+    """
+    Picks one element using the OS CSPRNG (safe for secret material).
+    """
+    return secrets.choice(items)
+
+
+def secure_token(length: int) -> str:
+    """
+    Returns `length` random bytes from the OS CSPRNG, URL-safe base64 encoded (no padding).
+    Suitable for session tokens, CSRF tokens, or API key seeds.
+    """
+    return secrets.token_urlsafe(length)
+
+
+def random_delay(min_ms: int, max_ms: int) -> timedelta:
+    """
+    Uniformly random duration in [min_ms, max_ms], to blur timing patterns of automated loops.
+    """
+    if min_ms > max_ms:
+        raise ValueError("min_ms must not exceed max_ms")
+    return timedelta(milliseconds=_sysrand.uniform(min_ms, max_ms))
+
+
+def jitter(base: timedelta, jitter_pct: float) -> timedelta:
+    """
+    `base` plus or minus up to `jitter_pct` (0.2 = 20%) relative noise, never negative.
+    """
+    factor = 1 + _sysrand.uniform(-jitter_pct, jitter_pct)
+    return max(timedelta(0), base * factor)
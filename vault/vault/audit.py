@@ -0,0 +1,161 @@
+"""
+audit.py
+
+Append-only audit trail of vault operations, stored as NDJSON (one JSON object per line):
+
+    {"ts": 1700000000000, "event": "identity_updated", "item_id": "...", "fields_changed": ["email"]}
+
+Events only carry identifiers and field names, never secret values.
+"""
+
+import json
+import os
+import time
+from dataclasses import asdict, dataclass, field
+from datetime import datetime, timezone
+from typing import ClassVar, Dict, List, Optional, Tuple, Type
+
+AUDIT_LOG_PATH = "audit.ndjson"
+
+
+# ------------------------
+# Events
+# ------------------------
+_EVENT_TYPES: Dict[str, Type["VaultEvent"]] = {}
+
+
+@dataclass
+class VaultEvent:
+    kind: ClassVar[str] = ""
+
+    def __init_subclass__(cls, **kwargs):
+        super().__init_subclass__(**kwargs)
+        _EVENT_TYPES[cls.kind] = cls
+
+
+@dataclass
+class IdentityCreated(VaultEvent):
+    kind: ClassVar[str] = "identity_created"
+    item_id: str
+
+
+@dataclass
+class IdentityUpdated(VaultEvent):
+    kind: ClassVar[str] = "identity_updated"
+    item_id: str
+    fields_changed: List[str] = field(default_factory=list)
+
+
+@dataclass
+class SecretCreated(VaultEvent):
+    kind: ClassVar[str] = "secret_created"
+    secret_id: str
+    item_id: str
+
+
+@dataclass
+class SecretUpdated(VaultEvent):
+    kind: ClassVar[str] = "secret_updated"
+    secret_id: str
+    fields_changed: List[str] = field(default_factory=list)
+
+
+@dataclass
+class SecretRotated(VaultEvent):
+    kind: ClassVar[str] = "secret_rotated"
+    secret_id: str
+
+
+@dataclass
+class FileAdded(VaultEvent):
+    kind: ClassVar[str] = "file_added"
+    file_id: str
+    item_id: str
+
+
+@dataclass
+class MasterKeyRotated(VaultEvent):
+    """Every wrapped DEK was re-wrapped under a new master key (rotate_key / change_password)."""
+    kind: ClassVar[str] = "master_key_rotated"
+    entries: int
+
+
+@dataclass
+class ItemArchived(VaultEvent):
+    kind: ClassVar[str] = "item_archived"
+    item_id: str
+
+
+@dataclass
+class ItemRestored(VaultEvent):
+    kind: ClassVar[str] = "item_restored"
+    item_id: str
+
+
+@dataclass
+class ItemPurged(VaultEvent):
+    """An archived item was permanently deleted by vacuum(), with these secrets and files."""
+    kind: ClassVar[str] = "item_purged"
+    item_id: str
+    secret_ids: List[str] = field(default_factory=list)
+    file_ids: List[str] = field(default_factory=list)
+
+
+@dataclass
+class AliasCreated(VaultEvent):
+    kind: ClassVar[str] = "alias_created"
+    alias_id: int
+    email: str
+
+
+@dataclass
+class AliasLinked(VaultEvent):
+    """An item was linked to a SimpleLogin alias, or unlinked (alias_id None)."""
+    kind: ClassVar[str] = "alias_linked"
+    item_id: str
+    alias_id: Optional[int] = None
+
+
+@dataclass
+class AliasDisabled(VaultEvent):
+    kind: ClassVar[str] = "alias_disabled"
+    alias_id: int
+
+
+# ------------------------
+# Log
+# ------------------------
+class AuditLog:
+    def __init__(self, path: str = AUDIT_LOG_PATH):
+        self.path = path
+
+    def record(self, event: VaultEvent):
+        entry = {"ts": int(time.time() * 1000), "event": event.kind, **asdict(event)}
+        os.makedirs(os.path.dirname(self.path) or ".", exist_ok=True)
+        with open(self.path, "a", encoding="utf-8") as f:
+            f.write(json.dumps(entry) + "\n")
+
+    def replay(self, since: Optional[datetime] = None) -> List[Tuple[datetime, VaultEvent]]:
+        """
+        Read back recorded events in order, optionally only those at or after `since`.
+        Unknown event kinds (e.g. from a newer version) are skipped.
+        """
+        if not os.path.exists(self.path):
+            return []
+
+        since_ms = int(since.timestamp() * 1000) if since else None
+        events = []
+        with open(self.path, "r", encoding="utf-8") as f:
+            for line in f:
+                if not line.strip():
+                    continue
+                entry = json.loads(line)
+                ts_ms = entry.pop("ts")
+                if since_ms is not None and ts_ms < since_ms:
+                    continue
+                event_cls = _EVENT_TYPES.get(entry.pop("event"))
+                if event_cls is None:
+                    continue
+                ts = datetime.fromtimestamp(ts_ms / 1000, tz=timezone.utc)
+                events.append((ts, event_cls(**entry)))
+        return events
@@ -0,0 +1,161 @@
+"""
+errors.py
+
+Exceptions shared by the vault and the email alias clients.
+All of them derive from VaultError so callers can catch one type.
+
+str(err) is a single line meant for logs; err.to_user_message() is a short
+phrasing without internal details, meant for end users. err.is_retryable()
+and err.is_auth_error() let retry layers and UIs decide without matching types.
+"""
+
+import re
+from http import HTTPStatus
+from typing import Optional
+
+import httpx
+
+BODY_PREVIEW_LEN = 200
+RAW_BODY_CAPTURE_LEN = 2048
+RETRYABLE_STATUSES = {429, 500, 502, 503, 504}
+
+_WHITESPACE = re.compile(r"\s+")
+_MEMORY_ADDRESS = re.compile(r" at 0x[0-9a-fA-F]+")
+
+
+def _one_line(text: str) -> str:
+    return _WHITESPACE.sub(" ", text).strip()
+
+
+def _preview(text: str, limit: int = BODY_PREVIEW_LEN) -> str:
+    text = _one_line(text)
+    return text if len(text) <= limit else text[:limit] + "..."
+
+
+class VaultError(Exception):
+    def to_user_message(self) -> str:
+        return _one_line(str(self))
+
+    def is_retryable(self) -> bool:
+        """True if the same request may succeed when simply sent again later."""
+        return False
+
+    def is_auth_error(self) -> bool:
+        """True if the credentials were rejected (retrying won't help, re-authenticating might)."""
+        return False
+
+
+class InvalidArgument(VaultError, ValueError):
+    """Rejected locally, before any request was made."""
+
+
+class NotFound(VaultError, KeyError):
+    """The requested object does not exist. Also a KeyError, for existing `except KeyError` callers."""
+
+    # KeyError would render the message quoted
+    __str__ = Exception.__str__
+
+
+class NotSupported(VaultError):
+    """The server (version) doesn't offer the requested feature."""
+
+
+class Unauthorized(VaultError):
+    """Credentials were rejected; the message says why and is safe to show to users."""
+
+    def is_auth_error(self) -> bool:
+        return True
+
+
+class VaultLocked(VaultError):
+    def __init__(self):
+        super().__init__("Vault locked")
+
+    def to_user_message(self) -> str:
+        return "The vault is locked. Unlock it and try again."
+
+
+class KeyRotationError(VaultError):
+    """Rotation stopped part-way; `rotated` entries were already re-wrapped and committed."""
+
+    def __init__(self, rotated: int, cause: Exception):
+        self.rotated = rotated
+        self.cause = cause
+        super().__init__(f"Key rotation failed after {rotated} entries: {type(cause).__name__}: {cause}")
+
+
+class MfaRequired(Unauthorized):
+    """Password login needs a second factor; finish it with `mfa_key`."""
+
+    def __init__(self, mfa_key: Optional[str] = None):
+        self.mfa_key = mfa_key
+        super().__init__("MFA is enabled on this account; complete login with the MFA key")
+
+
+class ApiError(VaultError):
+    def __init__(self, status: int, body: str):
+        self.status = status
+        self.body = body
+        try:
+            reason = HTTPStatus(status).phrase
+        except ValueError:
+            reason = "Unknown Status"
+        super().__init__(f"HTTP {status}: {reason} - API response: {_preview(body)}")
+
+    def is_retryable(self) -> bool:
+        return self.status in RETRYABLE_STATUSES
+
+    def is_auth_error(self) -> bool:
+        return self.status in (401, 403)
+
+    def to_user_message(self) -> str:
+        if self.status == 401:
+            return "Your API key is invalid or has expired."
+        if self.status == 403:
+            return "Access denied."
+        if self.status == 404:
+            return "The requested item was not found."
+        if self.status == 429:
+            return "Too many requests. Please wait and try again."
+        if self.status >= 500:
+            return "The service is temporarily unavailable. Please try again later."
+        return f"The request was rejected (HTTP {self.status})."
+
+
+class NetworkError(VaultError):
+    def __init__(self, cause: Exception):
+        self.cause = cause
+        # walk the cause chain, dropping reprs like "<... at 0x7f...>"
+        parts = []
+        err = cause
+        while err is not None and len(parts) < 5:
+            parts.append(_MEMORY_ADDRESS.sub("", _one_line(f"{type(err).__name__}: {err}")))
+            err = err.__cause__ or err.__context__
+        super().__init__("Network error: " + " <- ".join(parts))
+
+    def is_retryable(self) -> bool:
+        # timeouts, refused connections and dropped responses; TLS or invalid-URL errors won't fix themselves
+        return isinstance(self.cause, (httpx.TimeoutException, httpx.ConnectError, httpx.RemoteProtocolError))
+
+    def to_user_message(self) -> str:
+        return "Could not reach the server. Check your connection and try again."
+
+
+class DeserializationError(VaultError):
+    def __init__(self, message: str, raw_body: str):
+        self.message = message
+        self.raw_body = raw_body[:RAW_BODY_CAPTURE_LEN]
+        super().__init__(f"Unexpected response format: {_one_line(message)} - raw body: {_preview(raw_body)}")
+
+    def to_user_message(self) -> str:
+        return "The server returned a response this client does not understand."
+
+
+class ResponseTooLarge(VaultError):
+    def __init__(self, actual_bytes: int, max_bytes: int):
+        self.actual_bytes = actual_bytes
+        self.max_bytes = max_bytes
+        super().__init__(f"Response body too large: {actual_bytes} bytes (limit {max_bytes})")
+
+    def to_user_message(self) -> str:
+        return "The server sent an unexpectedly large response."
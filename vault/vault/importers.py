@@ -0,0 +1,231 @@
+"""
+importers.py
+
+Bulk import of logins exported from other password managers (CSV, KeePass XML).
+
+Each login becomes an identity item (domain, title, notes) plus a "password"
+secret holding the username and password; the identity's site_specific
+"password_ref" points at that secret.
+"""
+
+import csv
+import secrets
+import xml.etree.ElementTree as ET
+from dataclasses import dataclass, field
+from enum import Enum
+from typing import Dict, Iterable, List, Optional, Tuple
+from urllib.parse import urlsplit
+
+from profile.types import SiteType
+from vault.errors import InvalidArgument, VaultLocked
+from vault.vault import Vault
+
+
+# ------------------------
+# Options and results
+# ------------------------
+class ConflictStrategy(str, Enum):
+    """
+    What to do when a login for the same domain and username already exists.
+    """
+    SKIP = "skip"  # keep the existing entry untouched
+    OVERWRITE = "overwrite"  # replace password and notes of the existing secret
+    KEEP_BOTH = "keep_both"  # import as an additional entry
+
+
+@dataclass
+class CsvColumnMap:
+    """
+    CSV header for each field, matched case-insensitively; None skips the field. Only the
+    url column must exist, absent optional columns are skipped. The defaults are generic;
+    use a preset for a specific password manager's export.
+    """
+    url: str = "url"
+    username: Optional[str] = "username"
+    password: Optional[str] = "password"
+    notes: Optional[str] = "notes"
+    title: Optional[str] = "name"
+
+    @classmethod
+    def bitwarden(cls) -> "CsvColumnMap":
+        return cls(url="login_uri", username="login_username", password="login_password", notes="notes", title="name")
+
+    @classmethod
+    def onepassword(cls) -> "CsvColumnMap":
+        return cls(url="url", username="username", password="password", notes="notes", title="title")
+
+    @classmethod
+    def lastpass(cls) -> "CsvColumnMap":
+        return cls(url="url", username="username", password="password", notes="extra", title="name")
+
+    @classmethod
+    def chrome(cls) -> "CsvColumnMap":
+        return cls(url="url", username="username", password="password", notes="note", title="name")
+
+
+@dataclass
+class ImportReport:
+    imported: int = 0
+    updated: int = 0
+    skipped: int = 0
+    errors: List[Tuple[int, str]] = field(default_factory=list)  # (record number, reason)
+
+
+@dataclass
+class LoginRecord:
+    line: int  # position in the source, for error reporting
+    url: str
+    title: Optional[str] = None
+    username: Optional[str] = None
+    password: Optional[str] = None
+    notes: Optional[str] = None
+    tags: List[str] = field(default_factory=list)
+
+
+# ------------------------
+# Importers
+# ------------------------
+def bulk_import_from_csv(
+        vault: Vault,
+        path: str,
+        column_map: Optional[CsvColumnMap] = None,
+        on_conflict: ConflictStrategy = ConflictStrategy.SKIP,
+) -> ImportReport:
+    """
+    Imports every row of a CSV export. Rows without a usable URL are reported in
+    `errors` and skipped; they don't abort the import.
+    """
+    columns = column_map or CsvColumnMap()
+    with open(path, newline="", encoding="utf-8-sig") as f:
+        reader = csv.DictReader(f)
+        # casefolded name -> header as written in the file
+        headers = {h.strip().casefold(): h for h in reader.fieldnames or []}
+        if columns.url.casefold() not in headers:
+            raise InvalidArgument(f"CSV is missing the URL column: {columns.url}")
+
+        def cell(row: Dict[str, str], column: Optional[str]) -> Optional[str]:
+            header = headers.get(column.casefold()) if column else None
+            value = (row.get(header) or "").strip() if header else ""
+            return value or None
+
+        # header is line 1
+        records = [
+            LoginRecord(
+                line=line,
+                url=cell(row, columns.url) or "",
+                title=cell(row, columns.title),
+                username=cell(row, columns.username),
+                password=cell(row, columns.password),
+                notes=cell(row, columns.notes),
+            )
+            for line, row in enumerate(reader, start=2)
+        ]
+    return import_logins(vault, records, on_conflict)
+
+
+def import_from_keepass_xml(vault: Vault, path: str, on_conflict: ConflictStrategy = ConflictStrategy.SKIP) -> ImportReport:
+    """
+    Imports a KeePass "Export to XML" file. The export is plain text, so no master password
+    is needed. Each entry's group path (below the root group) becomes its tags; entry
+    history and the recycle bin are ignored.
+    """
+    try:
+        tree = ET.parse(path)
+    except ET.ParseError as e:
+        raise InvalidArgument(f"Not a valid KeePass XML export: {e}") from e
+
+    root = tree.getroot()
+    top_group = root.find("Root/Group")
+    if root.tag != "KeePassFile" or top_group is None:
+        raise InvalidArgument("Not a KeePass XML export (missing KeePassFile/Root/Group)")
+    recycle_bin = (root.findtext("Meta/RecycleBinUUID") or "").strip()
+
+    records: List[LoginRecord] = []
+
+    def walk(group: ET.Element, path: List[str]):
+        for entry in group.findall("Entry"):
+            values = {s.findtext("Key"): (s.findtext("Value") or "").strip() for s in entry.findall("String")}
+            records.append(LoginRecord(
+                line=len(records) + 1,
+                url=values.get("URL", ""),
+                title=values.get("Title") or None,
+                username=values.get("UserName") or None,
+                password=values.get("Password") or None,
+                notes=values.get("Notes") or None,
+                tags=list(path),
+            ))
+        for sub in group.findall("Group"):
+            if recycle_bin and (sub.findtext("UUID") or "").strip() == recycle_bin:
+                continue
+            walk(sub, path + [(sub.findtext("Name") or "").strip()])
+
+    walk(top_group, [])
+    return import_logins(vault, records, on_conflict)
+
+
+def import_logins(vault: Vault, records: Iterable[LoginRecord], on_conflict: ConflictStrategy = ConflictStrategy.SKIP) -> ImportReport:
+    """
+    Stores parsed login records, shared by all importers.
+    """
+    if vault.is_locked:
+        raise VaultLocked()
+
+    existing = _existing_logins(vault)
+    report = ImportReport()
+    for record in records:
+        domain = _domain_from_url(record.url)
+        if not domain:
+            report.errors.append((record.line, f"invalid or missing URL: {record.url!r}"))
+            continue
+
+        key = (domain, (record.username or "").casefold())
+        if key in existing and on_conflict == ConflictStrategy.SKIP:
+            report.skipped += 1
+            continue
+        if key in existing and on_conflict == ConflictStrategy.OVERWRITE:
+            vault.update_secret(existing[key], {"password": record.password, "notes": record.notes})
+            report.updated += 1
+            continue
+
+        item_id = f"item-{secrets.token_hex(8)}"
+        secret_id = f"sec-{secrets.token_hex(8)}"
+        username = record.username
+        pii = {
+            "email": username if username and "@" in username else None,
+            "notes": record.notes,
+            "tags": record.tags,
+            "site_specific": {"password_ref": secret_id},
+        }
+        vault.create_identity(item_id, domain, record.title or domain, pii, site_type=SiteType.from_domain_hint(domain).value)
+        vault.create_secret(secret_id, item_id, "password", username, record.password, notes=record.notes)
+        existing[key] = secret_id
+        report.imported += 1
+    return report
+
+
+# ------------------------
+# Helpers
+# ------------------------
+def _domain_from_url(url: str) -> Optional[str]:
+    url = url.strip()
+    if not url:
+        return None
+    try:
+        hostname = urlsplit(url if "://" in url else f"https://{url}").hostname
+    except ValueError:
+        return None
+    return hostname or None
+
+
+def _existing_logins(vault: Vault) -> Dict[Tuple[str, str], str]:
+    """
+    (domain, casefolded username) -> secret_id for the password secrets already in the vault.
+    """
+    logins = {}
+    for item in vault.list_items():
+        for meta in vault.list_secrets_for_item(item["item_id"]):
+            if meta["secret_type"] != "password":
+                continue
+            secret = vault.load_secret(meta["secret_id"])
+            logins.setdefault((item["domain"], (secret.username or "").casefold()), meta["secret_id"])
+    return logins
@@ -16,13 +16,15 @@ encryption if you want, add code signing, and run security audits.
 import os
 import json
 import hashlib
+import hmac
 import base64
 import time
 import secrets
-from dataclasses import dataclass, asdict
-from typing import Optional, Tuple
+from dataclasses import dataclass, asdict, fields
+from typing import Dict, List, Optional, Tuple
 
 # Crypto primitives (requires `cryptography`)
+from cryptography.exceptions import InvalidTag
 from cryptography.hazmat.primitives.ciphers.aead import AESGCM
 from cryptography.hazmat.primitives import hashes
 from cryptography.hazmat.primitives.kdf.hkdf import HKDF
@@ -36,9 +38,30 @@ except Exception:
     HAVE_ARGON2 = False
 
 # SQLAlchemy ORM
-from sqlalchemy import Column, Integer, LargeBinary, String, Text, create_engine
+from sqlalchemy import Column, Integer, LargeBinary, String, Text, create_engine, func, inspect, text
 from sqlalchemy.orm import declarative_base, sessionmaker
 
+from profile.types import SiteType
+from profile_secrets.generator import generate_secure_password
+from profile_secrets.secret_string import SecretString
+from profile_secrets.strength import PasswordStrength, normalized_levenshtein, password_strength
+from vault.errors import InvalidArgument, KeyRotationError, Unauthorized, VaultError, VaultLocked
+from vault.audit import (
+    AliasDisabled,
+    AliasLinked,
+    AuditLog,
+    FileAdded,
+    IdentityCreated,
+    IdentityUpdated,
+    ItemArchived,
+    ItemPurged,
+    ItemRestored,
+    MasterKeyRotated,
+    SecretCreated,
+    SecretRotated,
+    SecretUpdated,
+)
+
 
 # ------------------------
 # Config / Constants
@@ -50,6 +73,9 @@ MK_LEN = 32  # 256-bit master key
 DEK_LEN = 32  # 256-bit data encryption key
 WRAP_INFO = b"vault-dek-wrap-v1"  # AAD for DEK wrapping
 ITERATIONS_PBKDF2 = 480_000  # fallback (tune for device). Argon2 preferred.
+BACKUP_VERSION = 1  # first byte of an encrypted backup file
+BACKUP_MAC_LEN = 32  # HMAC-SHA256 trailer
+DEFAULT_RETENTION_DAYS = 30  # archived items older than this are purged by vacuum()
 
 Base = declarative_base()
 
@@ -165,6 +191,23 @@ def unwrap_dek(mk: bytes, wrapped_blob: bytes, aad: Optional[bytes] = None) -> b
     return dek
 
 
+def _unwraps_with(mk: bytes, wrapped_blob: bytes, aad: bytes) -> bool:
+    try:
+        unwrap_dek(mk, wrapped_blob, aad=aad)
+        return True
+    except InvalidTag:
+        return False
+
+
+def _backup_keys(key: bytes) -> Tuple[bytes, bytes]:
+    """
+    Split a 32-byte backup key into independent encryption and MAC keys.
+    """
+    if len(key) != 32:
+        raise InvalidArgument("Backup key must be 32 bytes")
+    return derive_wrap_key(key, b"vault-backup-enc-v1"), derive_wrap_key(key, b"vault-backup-mac-v1")
+
+
 # ------------------------
 # Blob storage: write/read content-addressed encrypted blobs
 # ------------------------
@@ -205,6 +248,8 @@ class Item(Base):
     updated_at = Column(Integer)
     version = Column(Integer)
     tombstoned = Column(Integer, default=0)
+    alias_id = Column(Integer)  # linked SimpleLogin alias, if any
+    alias_disabled = Column(Integer, default=0)
 
 
 class Secret(Base):
@@ -233,6 +278,14 @@ class File(Base):
     updated_at = Column(Integer)
 
 
+class OrphanedBlob(Base):
+    """Blobs this vault stopped referencing (superseded or purged); vacuum() deletes them."""
+    __tablename__ = "orphaned_blobs"
+
+    blob_hash = Column(String, primary_key=True)
+    orphaned_at = Column(Integer)
+
+
 class Meta(Base):
     __tablename__ = "meta"
 
@@ -240,6 +293,16 @@ class Meta(Base):
     value = Column(Text)
 
 
+# Columns added after the initial schema. create_all() does not alter existing
+# tables, so these are patched into older databases on startup.
+ADDED_COLUMNS = {
+    "items": [
+        ("alias_id", "INTEGER"),
+        ("alias_disabled", "INTEGER DEFAULT 0"),
+    ],
+}
+
+
 # ------------------------
 # High-level blob creation helpers
 # ------------------------
@@ -312,20 +375,48 @@ class FileBlobMeta:
     # The actual file bytes are encrypted and stored as a separate blob; this JSON contains metadata and pointers if needed.
 
 
+@dataclass
+class VaultStats:
+    total_profiles: int
+    archived_profiles: int
+    profiles_by_site_type: Dict[str, int]
+    profiles_by_trust_level: Dict[int, int]
+    linked_aliases: int
+    unlinked_profiles: int
+
+
+@dataclass
+class VacuumReport:
+    files_deleted: int = 0
+    bytes_freed: int = 0
+    profiles_compacted: int = 0
+
+
 # ------------------------
 # High-level APIs
 # ------------------------
 class Vault:
-    def __init__(self, db_path: str = DB_PATH):
+    def __init__(self, db_path: str = DB_PATH, audit_log: Optional[AuditLog] = None):
         ensure_dirs()
         self.db_path = db_path
+        self.audit = audit_log or AuditLog()
         self.engine = create_engine(f"sqlite:///{self.db_path}", connect_args={"check_same_thread": False})
         self.Session = sessionmaker(bind=self.engine, expire_on_commit=False)
         Base.metadata.create_all(self.engine)
-        self.mk: Optional[bytes] = None
+        self._upgrade_schema()
+        self.mk: Optional[bytearray] = None  # mutable so lock() can overwrite it
         self.salt: Optional[bytes] = None
         self._ensure_salt()
 
+    def _upgrade_schema(self):
+        inspector = inspect(self.engine)
+        with self.engine.begin() as conn:
+            for table, columns in ADDED_COLUMNS.items():
+                existing = {c["name"] for c in inspector.get_columns(table)}
+                for name, ddl in columns:
+                    if name not in existing:
+                        conn.execute(text(f"ALTER TABLE {table} ADD COLUMN {name} {ddl}"))
+
     def _ensure_salt(self):
         # salt stored in meta table; if not present, will be created
         with self.Session() as session:
@@ -343,15 +434,88 @@ class Vault:
         """
         Derive MK and hold in memory. In production, wrap MK with OS keystore for biometric unlocking.
         """
-        self.mk = derive_master_key(password, self.salt, use_argon2=use_argon2)
+        self.lock()
+        self.mk = bytearray(derive_master_key(password, self.salt, use_argon2=use_argon2))
 
     def lock(self):
-        # Zero out mk if possible (best effort in Python)
+        """
+        Overwrites the master key with zeros before dropping it. Best effort: transient
+        copies made by the crypto library or during derivation can't be reached from here.
+        """
+        if self.mk is not None:
+            self.mk[:] = bytes(len(self.mk))
         self.mk = None
 
+    @property
+    def is_locked(self) -> bool:
+        return self.mk is None
+
+    def _require_unlocked(self):
+        if self.mk is None:
+            raise VaultLocked()
+
+    @staticmethod
+    def _orphan_blobs(session, hashes):
+        # part of the caller's transaction, so a rolled-back update orphans nothing
+        ts = now_ms()
+        for h in hashes:
+            if h:
+                session.merge(OrphanedBlob(blob_hash=h, orphaned_at=ts))
+
+    def change_password(self, current_password: str, new_password: str, use_argon2: bool = True) -> int:
+        """
+        Re-wraps every DEK under the key derived from `new_password` and keeps the vault unlocked with it.
+        Raises Unauthorized if `current_password` is wrong (see rotate_key).
+        """
+        old_mk = derive_master_key(current_password, self.salt, use_argon2=use_argon2)
+        new_mk = derive_master_key(new_password, self.salt, use_argon2=use_argon2)
+        return self.rotate_key(old_mk, new_mk)
+
+    def rotate_key(self, old_key: bytes, new_key: bytes) -> int:
+        """
+        Re-wraps the DEK of every item, secret and file from `old_key` to `new_key`.
+        Blobs stay as they are (envelope encryption), and each entry is committed on its own.
+        Stops at the first failure with KeyRotationError; running it again resumes, since
+        entries already under `new_key` are skipped. Returns the number of entries under `new_key`.
+
+        Raises Unauthorized, before touching anything, when the vault is unlocked with a key other
+        than `old_key`, or when the first entry opens under neither key (wrong old key).
+        """
+        if len(old_key) != MK_LEN or len(new_key) != MK_LEN:
+            raise InvalidArgument(f"Master keys must be {MK_LEN} bytes")
+        if self.mk is not None and not hmac.compare_digest(bytes(self.mk), old_key):
+            raise Unauthorized("Current password or key does not match the unlocked vault")
+
+        # (model, id column, wrapped DEK column); the ID doubles as the AAD
+        targets = [(Item, "item_id", "detail_dek_wrap"), (Secret, "secret_id", "dek_wrap"), (File, "file_id", "dek_wrap")]
+        rotated = 0
+        with self.Session() as session:
+            for model, id_attr, wrap_attr in targets:
+                for row in session.query(model).all():
+                    wrapped = getattr(row, wrap_attr)
+                    if wrapped is None:
+                        continue
+                    aad = getattr(row, id_attr).encode()
+                    if rotated == 0 and not _unwraps_with(new_key, wrapped, aad) and not _unwraps_with(old_key, wrapped, aad):
+                        raise Unauthorized("Current password or key is wrong")
+                    try:
+                        if not _unwraps_with(new_key, wrapped, aad):
+                            dek = unwrap_dek(old_key, wrapped, aad=aad)
+                            setattr(row, wrap_attr, wrap_dek(new_key, dek, aad=aad))
+                            session.commit()
+                    except Exception as e:
+                        session.rollback()
+                        raise KeyRotationError(rotated, e) from e
+                    rotated += 1
+
+        self.lock()
+        self.mk = bytearray(new_key)
+        self.audit.record(MasterKeyRotated(entries=rotated))
+        return rotated
+
     # ---- Identity operations ----
-    def create_identity(self, item_id: str, domain: str, name: str, pii: dict, site_type: str = "generic", trust_level: int = 0):
-        assert self.mk is not None, "Vault locked"
+    def create_identity(self, item_id: str, domain: str, name: str, pii: dict, site_type: str = "generic", trust_level: int = 0, alias_id: Optional[int] = None):
+        self._require_unlocked()
         blob = IdentityBlob(
             schema="vault.identity@1",
             item_id=item_id,
@@ -382,13 +546,16 @@ class Vault:
                 created_at=ts,
                 updated_at=ts,
                 version=1,
-                tombstoned=0
+                tombstoned=0,
+                alias_id=alias_id,
+                alias_disabled=0
             ))
             session.commit()
+        self.audit.record(IdentityCreated(item_id=item_id))
         return blob_hash
 
     def load_identity(self, item_id: str) -> IdentityBlob:
-        assert self.mk is not None, "Vault locked"
+        self._require_unlocked()
         with self.Session() as session:
             item = session.get(Item, item_id)
             if not item:
@@ -401,7 +568,7 @@ class Vault:
         """
         Merge provided fields into the stored identity, re-encrypt, and bump version/update timestamps.
         """
-        assert self.mk is not None, "Vault locked"
+        self._require_unlocked()
         with self.Session() as session:
             item = session.get(Item, item_id)
             if not item:
@@ -425,16 +592,61 @@ class Vault:
             new_blob = json.dumps(obj).encode("utf-8")
             blob_hash, wrapped_dek = encrypt_and_store_blob(self.mk, new_blob, aad=item_id.encode())
 
+            self._orphan_blobs(session, [item.detail_blob_hash])
             item.detail_blob_hash = blob_hash
             item.detail_dek_wrap = wrapped_dek
             item.updated_at = ts
             item.version = (item.version or 1) + 1
             session.commit()
+            changed = sorted(k for k, v in updates.items() if v is not None)
+            self.audit.record(IdentityUpdated(item_id=item_id, fields_changed=changed))
             return IdentityBlob(**obj)
 
+    def archive_item(self, item_id: str, archived: bool = True):
+        """
+        Soft-deletes (or, with archived=False, restores) an item. Archived items keep their
+        secrets and files until vacuum() purges them after the retention period.
+        """
+        with self.Session() as session:
+            item = session.get(Item, item_id)
+            if not item:
+                raise KeyError("Item not found")
+            item.tombstoned = int(archived)
+            item.updated_at = now_ms()
+            session.commit()
+        self.audit.record(ItemArchived(item_id=item_id) if archived else ItemRestored(item_id=item_id))
+
+    def link_alias(self, item_id: str, alias_id: Optional[int]):
+        """
+        Associate (or, with None, dissociate) a SimpleLogin alias with an item.
+        """
+        with self.Session() as session:
+            item = session.get(Item, item_id)
+            if not item:
+                raise KeyError("Item not found")
+            item.alias_id = alias_id
+            item.alias_disabled = 0
+            item.updated_at = now_ms()
+            session.commit()
+        self.audit.record(AliasLinked(item_id=item_id, alias_id=alias_id))
+
+    def mark_alias_disabled(self, alias_id: int, disabled: bool = True) -> int:
+        """
+        Flag items whose linked alias was disabled (but not deleted) upstream.
+        Returns the number of items updated.
+        """
+        with self.Session() as session:
+            count = session.query(Item).filter(Item.alias_id == alias_id).update(
+                {"alias_disabled": int(disabled), "updated_at": now_ms()}
+            )
+            session.commit()
+        if disabled:
+            self.audit.record(AliasDisabled(alias_id=alias_id))
+        return count
+
     # ---- Secret operations ----
     def create_secret(self, secret_id: str, item_id: str, secret_type: str, username: Optional[str], password: Optional[str], totp_uri: Optional[str] = None, notes: Optional[str] = None):
-        assert self.mk is not None, "Vault locked"
+        self._require_unlocked()
         blob = SecretBlob(
             schema="vault.secret@1",
             secret_id=secret_id,
@@ -460,10 +672,31 @@ class Vault:
                 updated_at=ts
             ))
             session.commit()
+        self.audit.record(SecretCreated(secret_id=secret_id, item_id=item_id))
         return blob_hash
 
+    def generate_and_store(self, item_id: str, length: Optional[int] = None, username: Optional[str] = None) -> Tuple[str, SecretString]:
+        """
+        Generates a password and saves it as a new secret of `item_id` in one step, so no
+        secret ID is handed out before the secret exists. Length defaults to the item's
+        recommended length. Returns (secret_id, password) for one-time display.
+        """
+        self._require_unlocked()
+        with self.Session() as session:
+            item = session.get(Item, item_id)
+            if not item:
+                raise KeyError("Item not found")
+            domain = item.domain
+
+        if length is None:
+            length = SiteType.from_domain_hint(domain).recommended_password_length()
+        password = generate_secure_password(length)
+        secret_id = f"sec-{secrets.token_hex(8)}"
+        self.create_secret(secret_id, item_id, "password", username, password)
+        return secret_id, SecretString(password)
+
     def load_secret(self, secret_id: str) -> SecretBlob:
-        assert self.mk is not None, "Vault locked"
+        self._require_unlocked()
         with self.Session() as session:
             secret = session.get(Secret, secret_id)
             if not secret:
@@ -476,7 +709,7 @@ class Vault:
         """
         Merge provided fields into the stored secret, re-encrypt, and update timestamps.
         """
-        assert self.mk is not None, "Vault locked"
+        self._require_unlocked()
         with self.Session() as session:
             secret = session.get(Secret, secret_id)
             if not secret:
@@ -493,15 +726,89 @@ class Vault:
             new_blob = json.dumps(obj).encode("utf-8")
             blob_hash, wrapped_dek = encrypt_and_store_blob(self.mk, new_blob, aad=secret_id.encode())
 
+            self._orphan_blobs(session, [secret.blob_hash])
             secret.blob_hash = blob_hash
             secret.dek_wrap = wrapped_dek
             secret.updated_at = ts
             session.commit()
+            changed = sorted(k for k, v in updates.items() if v is not None)
+            if "password" in changed:
+                self.audit.record(SecretRotated(secret_id=secret_id))
+            else:
+                self.audit.record(SecretUpdated(secret_id=secret_id, fields_changed=changed))
             return SecretBlob(**obj)
 
+    # ---- Backup ----
+    def export_encrypted_backup(self, path: str, key: bytes) -> int:
+        """
+        Write every secret (decrypted, then re-encrypted under `key`) to a single backup file.
+        Format: version byte || AES-GCM(nonce || ciphertext) || HMAC-SHA256(version || ciphertext).
+        Returns the number of secrets exported.
+        """
+        self._require_unlocked()
+        enc_key, mac_key = _backup_keys(key)
+        with self.Session() as session:
+            rows = session.query(Secret).all()
+            entries = [
+                {"item_id": r.item_id, "secret_type": r.secret_type, "blob": asdict(self.load_secret(r.secret_id))}
+                for r in rows
+            ]
+
+        payload = json.dumps({"schema": "vault.backup@1", "secrets": entries}).encode("utf-8")
+        header = bytes([BACKUP_VERSION])
+        ciphertext, _nonce = aead_encrypt(enc_key, payload, aad=header)
+        mac = hmac.new(mac_key, header + ciphertext, hashlib.sha256).digest()
+        with open(path, "wb") as f:
+            f.write(header + ciphertext + mac)
+        return len(entries)
+
+    def import_encrypted_backup(self, path: str, key: bytes) -> int:
+        """
+        Restore secrets from a file written by export_encrypted_backup.
+        Secrets whose ID already exists are left untouched. Returns the number imported.
+        """
+        self._require_unlocked()
+        enc_key, mac_key = _backup_keys(key)
+        with open(path, "rb") as f:
+            data = f.read()
+
+        if len(data) < 1 + BACKUP_MAC_LEN or data[0] != BACKUP_VERSION:
+            raise VaultError("Unsupported or corrupt backup file")
+        header, ciphertext, mac = data[:1], data[1:-BACKUP_MAC_LEN], data[-BACKUP_MAC_LEN:]
+        expected = hmac.new(mac_key, header + ciphertext, hashlib.sha256).digest()
+        if not hmac.compare_digest(mac, expected):
+            raise VaultError("Backup integrity check failed (wrong key or tampered file)")
+        entries = json.loads(aead_decrypt(enc_key, ciphertext, aad=header).decode("utf-8"))["secrets"]
+
+        imported = 0
+        restored = []
+        with self.Session() as session:
+            for entry in entries:
+                blob = SecretBlob(**entry["blob"])
+                if session.get(Secret, blob.secret_id):
+                    continue
+                plaintext = json.dumps(asdict(blob)).encode("utf-8")
+                blob_hash, wrapped_dek = encrypt_and_store_blob(self.mk, plaintext, aad=blob.secret_id.encode())
+                ts = now_ms()
+                session.add(Secret(
+                    secret_id=blob.secret_id,
+                    item_id=entry["item_id"],
+                    blob_hash=blob_hash,
+                    dek_wrap=wrapped_dek,
+                    secret_type=entry["secret_type"],
+                    created_at=ts,
+                    updated_at=ts
+                ))
+                imported += 1
+                restored.append((blob.secret_id, entry["item_id"]))
+            session.commit()
+        for secret_id, item_id in restored:
+            self.audit.record(SecretCreated(secret_id=secret_id, item_id=item_id))
+        return imported
+
     # ---- File operations ----
     def add_file(self, file_id: str, item_id: str, filename: str, mime_type: str, file_bytes: bytes, description: Optional[str] = None):
-        assert self.mk is not None, "Vault locked"
+        self._require_unlocked()
         # store file bytes as a blob (separate from JSON metadata)
         file_blob_hash, file_wrapped_dek = encrypt_and_store_blob(self.mk, file_bytes, aad=file_id.encode())
         # metadata blob (small JSON) - could be inline in DB; we use files table meta only
@@ -521,10 +828,11 @@ class Vault:
             # mark item has_attachments
             session.query(Item).filter(Item.item_id == item_id).update({"has_attachments": 1, "updated_at": ts})
             session.commit()
+        self.audit.record(FileAdded(file_id=file_id, item_id=item_id))
         return file_blob_hash
 
     def load_file(self, file_id: str) -> bytes:
-        assert self.mk is not None, "Vault locked"
+        self._require_unlocked()
         with self.Session() as session:
             file_row = session.get(File, file_id)
             if not file_row:
@@ -533,19 +841,22 @@ class Vault:
             return plaintext
 
     # ---- Utility: list items ----
+    @staticmethod
+    def _item_summary(i: Item) -> dict:
+        return {
+            "item_id": i.item_id,
+            "domain": i.domain,
+            "title": i.title,
+            "created_at": i.created_at,
+            "updated_at": i.updated_at,
+            "alias_id": i.alias_id,
+            "alias_disabled": bool(i.alias_disabled),
+        }
+
     def list_items(self):
         with self.Session() as session:
             items = session.query(Item).order_by(Item.updated_at.desc()).all()
-            return [
-                {
-                    "item_id": i.item_id,
-                    "domain": i.domain,
-                    "title": i.title,
-                    "created_at": i.created_at,
-                    "updated_at": i.updated_at,
-                }
-                for i in items
-            ]
+            return [self._item_summary(i) for i in items]
 
     def list_secrets_for_item(self, item_id: str):
         """
@@ -555,6 +866,167 @@ class Vault:
             secrets_rows = session.query(Secret.secret_id, Secret.secret_type).filter(Secret.item_id == item_id).all()
             return [{"secret_id": sid, "secret_type": stype} for sid, stype in secrets_rows]
 
+    def find_by_tag(self, tag: str):
+        """
+        Return items whose identity is tagged `tag` (case-insensitive).
+        Tags live inside the encrypted identity blobs, so this requires an unlocked vault.
+        """
+        self._require_unlocked()
+        target = tag.casefold()
+        with self.Session() as session:
+            items = session.query(Item).order_by(Item.updated_at.desc()).all()
+            return [
+                self._item_summary(i)
+                for i in items
+                if any(t.casefold() == target for t in (self.load_identity(i.item_id).tags or []))
+            ]
+
+    def statistics(self) -> VaultStats:
+        """
+        Aggregate counts for a dashboard, computed from the items index only (works while locked).
+        Breakdowns and alias counts cover active (non-archived) items.
+        """
+        with self.Session() as session:
+            total = session.query(func.count(Item.item_id)).scalar() or 0
+            active = session.query(Item).filter((Item.tombstoned == 0) | (Item.tombstoned.is_(None)))
+            active_count = active.count()
+            linked = active.filter(Item.alias_id.isnot(None)).count()
+            by_site_type = dict(active.with_entities(Item.site_type, func.count()).group_by(Item.site_type).all())
+            by_trust_level = dict(active.with_entities(Item.trust_level, func.count()).group_by(Item.trust_level).all())
+            return VaultStats(
+                total_profiles=total,
+                archived_profiles=total - active_count,
+                profiles_by_site_type=by_site_type,
+                profiles_by_trust_level=by_trust_level,
+                linked_aliases=linked,
+                unlinked_profiles=active_count - linked,
+            )
+
+    def weak_password_items(self, below: PasswordStrength = PasswordStrength.STRONG):
+        """
+        Audit: return items that have at least one password secret rated below `below`.
+        """
+        self._require_unlocked()
+        with self.Session() as session:
+            rows = session.query(Secret.secret_id, Secret.item_id).filter(Secret.secret_type == "password").all()
+            weak_ids = set()
+            for sid, item_id in rows:
+                secret = self.load_secret(sid)
+                if secret.password and password_strength(secret.password) < below:
+                    weak_ids.add(item_id)
+            if not weak_ids:
+                return []
+            items = session.query(Item).filter(Item.item_id.in_(weak_ids)).order_by(Item.updated_at.desc()).all()
+            return [self._item_summary(i) for i in items]
+
+    # ---- Maintenance ----
+    def vacuum(self, retention_days: int = DEFAULT_RETENTION_DAYS) -> VacuumReport:
+        """
+        Periodic cleanup:
+        - purges items archived (archive_item) and not touched for `retention_days`, with their secrets and files
+        - re-stores identity blobs that carry keys IdentityBlob no longer defines
+        - deletes the blob files this vault orphaned (purged entries and versions superseded by updates)
+        - compacts the SQLite file
+        Only blobs recorded in this database's orphaned_blobs table are deleted, never other
+        files in the (shared) blob directory, so other vaults' data is safe.
+        Purged items and compacted identities are recorded in the audit log.
+        """
+        self._require_unlocked()
+        report = VacuumReport()
+        cutoff = now_ms() - retention_days * 24 * 3600 * 1000
+        known_keys = {f.name for f in fields(IdentityBlob)}
+
+        with self.Session() as session:
+            expired = [i.item_id for i in session.query(Item.item_id).filter(Item.tombstoned == 1, Item.updated_at < cutoff)]
+            if expired:
+                purged = {item_id: ItemPurged(item_id=item_id) for item_id in expired}
+                for sid, item_id in session.query(Secret.secret_id, Secret.item_id).filter(Secret.item_id.in_(expired)):
+                    purged[item_id].secret_ids.append(sid)
+                for fid, item_id in session.query(File.file_id, File.item_id).filter(File.item_id.in_(expired)):
+                    purged[item_id].file_ids.append(fid)
+                self._orphan_blobs(session, [h for (h,) in session.query(Item.detail_blob_hash).filter(Item.item_id.in_(expired))])
+                for model in (Secret, File):
+                    self._orphan_blobs(session, [h for (h,) in session.query(model.blob_hash).filter(model.item_id.in_(expired))])
+                for model in (Secret, File, Item):
+                    session.query(model).filter(model.item_id.in_(expired)).delete(synchronize_session=False)
+                session.commit()
+                for event in purged.values():
+                    self.audit.record(event)
+
+            compacted = []
+            for item in session.query(Item).all():
+                aad = item.item_id.encode()
+                obj = json.loads(decrypt_blob_with_wrapped_dek(self.mk, item.detail_blob_hash, item.detail_dek_wrap, aad=aad))
+                cleaned = {k: v for k, v in obj.items() if k in known_keys}
+                if cleaned != obj:
+                    compacted.append(IdentityUpdated(item_id=item.item_id, fields_changed=sorted(obj.keys() - cleaned.keys())))
+                    self._orphan_blobs(session, [item.detail_blob_hash])
+                    item.detail_blob_hash, item.detail_dek_wrap = encrypt_and_store_blob(self.mk, json.dumps(cleaned).encode("utf-8"), aad=aad)
+                    report.profiles_compacted += 1
+            session.commit()
+            for event in compacted:
+                self.audit.record(event)
+
+            referenced = {h for (h,) in session.query(Item.detail_blob_hash)}
+            referenced |= {h for (h,) in session.query(Secret.blob_hash)}
+            referenced |= {h for (h,) in session.query(File.blob_hash)}
+
+            for orphan in session.query(OrphanedBlob).all():
+                path = os.path.join(BLOBS_DIR, orphan.blob_hash[:2], orphan.blob_hash[2:] + ".enc")
+                if orphan.blob_hash not in referenced and os.path.exists(path):
+                    report.bytes_freed += os.path.getsize(path)
+                    os.remove(path)
+                    report.files_deleted += 1
+                session.delete(orphan)
+            session.commit()
+
+        # VACUUM can't run inside a transaction
+        with self.engine.connect().execution_options(isolation_level="AUTOCOMMIT") as conn:
+            conn.execute(text("VACUUM"))
+        return report
+
+    def _stored_passwords(self) -> List[Tuple[str, str]]:
+        with self.Session() as session:
+            sids = [sid for (sid,) in session.query(Secret.secret_id).filter(Secret.secret_type == "password").all()]
+        pairs = []
+        for sid in sids:
+            password = self.load_secret(sid).password
+            if password:
+                pairs.append((sid, password))
+        return pairs
+
+    def find_reused_passwords(self) -> List[Tuple[str, str]]:
+        """
+        Audit: pairs of secret IDs sharing exactly the same password (constant-time comparison).
+        """
+        self._require_unlocked()
+        stored = [(sid, pw.encode("utf-8")) for sid, pw in self._stored_passwords()]
+        return [
+            (sid_a, sid_b)
+            for i, (sid_a, pw_a) in enumerate(stored)
+            for sid_b, pw_b in stored[i + 1:]
+            if hmac.compare_digest(pw_a, pw_b)
+        ]
+
+    def find_similar_passwords(self, threshold: float = 0.8) -> List[Tuple[str, str, float]]:
+        """
+        Audit: pairs of secret IDs whose passwords are near-identical (normalized edit distance
+        similarity above `threshold`), most similar first. Exact duplicates are left out;
+        find_reused_passwords() reports those. O(n^2) in the number of passwords.
+        """
+        self._require_unlocked()
+        stored = self._stored_passwords()
+        similar = []
+        for i, (sid_a, pw_a) in enumerate(stored):
+            for sid_b, pw_b in stored[i + 1:]:
+                if hmac.compare_digest(pw_a.encode("utf-8"), pw_b.encode("utf-8")):
+                    continue
+                score = normalized_levenshtein(pw_a, pw_b)
+                if score > threshold:
+                    similar.append((sid_a, sid_b, score))
+        similar.sort(key=lambda t: t[2], reverse=True)
+        return similar
+
 
 # ------------------------
 # Quick demo (non-executed here)
@@ -1,6 +1,7 @@
 import asyncio
 import os
 import secrets
+from dataclasses import asdict
 from typing import Optional
 
 from dotenv import load_dotenv
@@ -9,7 +10,11 @@ from flask_cors import CORS
 
 from email_aliases.api import SimpleLoginClient
 from profile.generator import generate_username, generate_full_name
+from profile.models import PII
+from profile.types import SiteType
 from profile_secrets.generator import generate_secure_password
+from vault.audit import AliasCreated
+from vault.errors import InvalidArgument
 from vault.vault import Vault
 
 
@@ -51,7 +56,7 @@ class VaultService:
         self.vault.lock()
 
     def _require_unlocked(self):
-        if self.vault.mk is None:
+        if self.vault.is_locked:
             raise RuntimeError("Vault locked. Call /unlock first.")
 
     # ---- Generators ----
@@ -71,18 +76,41 @@ class VaultService:
 
     def create_alias(self, api_key: str, hostname: Optional[str] = None, mode: str = "word", note: Optional[str] = None):
         alias_obj = asyncio.run(self._create_alias_async(api_key, hostname, mode, note))
+        self.vault.audit.record(AliasCreated(alias_id=alias_obj.id, email=alias_obj.email))
         # ensure JSON-serializable
         if hasattr(alias_obj, "model_dump"):
             return alias_obj.model_dump()
         return alias_obj
 
+    async def _disable_alias_async(self, api_key: str, alias_id: int):
+        async with SimpleLoginClient(api_key=api_key) as client:
+            return await client.aliases.disable_alias(alias_id)
+
+    def disable_alias(self, api_key: str, alias_id: int):
+        """
+        Disable (not delete) an alias upstream and flag the vault items linked to it.
+        """
+        alias_obj = asyncio.run(self._disable_alias_async(api_key, alias_id))
+        self.vault.mark_alias_disabled(alias_id)
+        return alias_obj.model_dump()
+
+    async def _list_domains_async(self, api_key: str):
+        async with SimpleLoginClient(api_key=api_key) as client:
+            return await client.aliases.list_available_domains()
+
+    def list_alias_domains(self, api_key: str):
+        return [d.model_dump() for d in asyncio.run(self._list_domains_async(api_key))]
+
     # ---- Vault operations ----
-    def create_identity(self, domain: str, name: str, pii: dict, site_type: str = "generic", trust_level: int = 0, item_id: Optional[str] = None):
+    def create_identity(self, domain: str, name: str, pii: dict, site_type: str = "generic", trust_level: int = 0, item_id: Optional[str] = None, alias_id: Optional[int] = None):
         self._require_unlocked()
+        known_type = next((t for t in SiteType if t.value == site_type), None)
+        if known_type and not PII(dob=pii.get("dob")).age_appropriate_for_site(known_type):
+            raise InvalidArgument(f"Profile is younger than the minimum age ({known_type.minimum_age()}) for {known_type.display_name()} sites")
         iid = item_id or f"item-{secrets.token_hex(8)}"
         domain_str = _stringify_domain(domain)
         title_str = _stringify_title(name)
-        blob_hash = self.vault.create_identity(iid, domain_str, title_str, pii, site_type=site_type, trust_level=trust_level)
+        blob_hash = self.vault.create_identity(iid, domain_str, title_str, pii, site_type=site_type, trust_level=trust_level, alias_id=alias_id)
         return {"item_id": iid, "blob_hash": blob_hash}
 
     def update_identity(self, item_id: str, updates: dict):
@@ -113,10 +141,17 @@ class VaultService:
         self._require_unlocked()
         return self.vault.list_items()
 
+    def find_by_tag(self, tag: str):
+        self._require_unlocked()
+        return self.vault.find_by_tag(tag)
+
     def list_secrets_for_item(self, item_id: str):
         self._require_unlocked()
         return self.vault.list_secrets_for_item(item_id)
 
+    def statistics(self):
+        return self.vault.statistics()
+
 
 def create_app(service: Optional[VaultService] = None) -> Flask:
     load_dotenv()
@@ -142,7 +177,10 @@ def create_app(service: Optional[VaultService] = None) -> Flask:
     @app.post("/password")
     def password():
         data = request.get_json(force=True) if request.data else {}
-        length = int(data.get("length", 16))
+        if "length" not in data and data.get("domain"):
+            length = SiteType.from_domain_hint(data["domain"]).recommended_password_length()
+        else:
+            length = int(data.get("length", 16))
         pwd = svc.generate_password(length)
         return jsonify({"password": pwd})
 
@@ -156,6 +194,17 @@ def create_app(service: Optional[VaultService] = None) -> Flask:
         alias_resp = svc.create_alias(api_key, hostname=hostname, mode=mode, note=note)
         return jsonify({"alias": alias_resp})
 
+    @app.post("/alias/<int:alias_id>/disable")
+    def disable_alias(alias_id: int):
+        api_key = os.getenv("SL_API_KEY")
+        alias_resp = svc.disable_alias(api_key, alias_id)
+        return jsonify({"alias": alias_resp})
+
+    @app.get("/domains")
+    def alias_domains():
+        api_key = os.getenv("SL_API_KEY")
+        return jsonify({"domains": svc.list_alias_domains(api_key)})
+
     @app.post("/identity")
     def create_identity():
         data = request.get_json(force=True) if request.data else {}
@@ -171,6 +220,7 @@ def create_app(service: Optional[VaultService] = None) -> Flask:
             site_type=data.get("site_type", "generic"),
             trust_level=int(data.get("trust_level", 0)),
             item_id=data.get("item_id"),
+            alias_id=data.get("alias_id"),
         )
         return jsonify(res)
 
@@ -215,8 +265,15 @@ def create_app(service: Optional[VaultService] = None) -> Flask:
 
     @app.get("/items")
     def list_items():
+        tag = request.args.get("tag")
+        if tag:
+            return jsonify(svc.find_by_tag(tag))
         return jsonify(svc.list_items())
 
+    @app.get("/stats")
+    def stats():
+        return jsonify(asdict(svc.statistics()))
+
     @app.get("/items/<item_id>/secrets")
     def secrets_for_item(item_id):
         rows = svc.list_secrets_for_item(item_id)